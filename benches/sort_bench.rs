@@ -0,0 +1,51 @@
+extern crate rand;
+extern crate rust_data_structure;
+
+use rust_data_structure::sort::linear::LinearSort;
+use rust_data_structure::sort::parallel::ParallelSort;
+use rust_data_structure::vector::{Sort, Vector};
+use std::time::Instant;
+
+const SIZE: usize = 1_000_000;
+
+fn bench<T: Clone, F: Fn(&mut Vector<T>)>(name: &str, data: &Vector<T>, sort: F) {
+    let mut v = data.clone();
+    let start = Instant::now();
+
+    sort(&mut v);
+    println!("{:<16}{:>10.2?}", name, start.elapsed());
+}
+
+fn main() {
+    let mut keys = Vector::with_capacity(SIZE);
+    for _ in 0..SIZE {
+        let len = keys.len();
+        keys.insert(len, rand::random::<u32>());
+    }
+
+    println!("{} random u32 keys", SIZE);
+    bench("quick_sort", &keys, |v| v.quick_sort(|a, b| a.cmp(b)));
+    bench("lsd_radix_sort", &keys, |v| v.lsd_radix_sort(|x| *x));
+    bench("msd_radix_sort", &keys, |v| v.msd_radix_sort(|x| *x));
+    bench("counting_sort", &keys, |v| {
+        v.counting_sort(|x| (*x >> 12) as usize)
+    });
+    bench("bucket_sort", &keys, |v| v.bucket_sort(|x| *x as f64));
+    bench("par_merge_sort", &keys, |v| {
+        v.par_merge_sort(|a, b| a.cmp(b))
+    });
+    bench("par_quick_sort", &keys, |v| {
+        v.par_quick_sort(|a, b| a.cmp(b))
+    });
+
+    let mut wide = Vector::with_capacity(SIZE);
+    for _ in 0..SIZE {
+        let len = wide.len();
+        wide.insert(len, rand::random::<u64>());
+    }
+
+    println!("{} random u64 keys", SIZE);
+    bench("quick_sort", &wide, |v| v.quick_sort(|a, b| a.cmp(b)));
+    bench("lsd_radix_sort", &wide, |v| v.lsd_radix_sort(|x| *x));
+    bench("msd_radix_sort", &wide, |v| v.msd_radix_sort(|x| *x));
+}
@@ -3,7 +3,8 @@ mod height;
 mod node;
 pub mod search;
 
-use super::utility::malloc_val;
+use super::utility::{AllocErr, Allocator, Global};
+use std::fmt;
 use std::marker::PhantomData;
 use std::ptr::{self, NonNull};
 
@@ -11,123 +12,21 @@ type Ptr<T> = Option<NonNull<T>>;
 type NodePtr<T> = Ptr<BinNode<T>>;
 pub type RawBinTree<T> = BinTree<T, BinNode<T>>;
 
-pub trait Node<T>: Sized {
-    fn get(&mut self) -> &mut T;
-    fn parent(&self) -> Ptr<Self>;
-    fn lc(&self) -> Option<Box<Self>>;
-    fn rc(&self) -> Option<Box<Self>>;
-    fn insert_lc(&mut self);
-    fn insert_rc(&mut self);
-
-    unsafe fn swap(a: &mut Self, b: &mut Self) {
-        ptr::swap(a.get(), b.get());
-    }
-
-    fn is_root(&self) -> bool {
-        self.parent() == None
-    }
-
-    fn is_lc(&self) -> bool {
-        let self_ptr = self as *const Self;
-
-        unsafe {
-            if let Some(node) = self.parent() {
-                if let Some(lc) = node.as_ref().lc() {
-                    return self_ptr == Box::into_raw(lc);
-                }
-            }
-
-            false
-        }
-    }
-    
-    fn has_lc(&self) -> bool {
-        self.lc().is_some()
-    }
-
-    fn has_rc(&self) -> bool {
-        self.rc().is_some()
-    }
-
-    fn is_rc(&self) -> bool {
-        !self.is_lc() && !self.is_root()
-    }
-    fn is_leaf(&self) -> bool {
-        !self.has_lc() && !self.has_rc()
-    }
-
-    fn has_double_branch(&self) -> bool {
-        self.has_lc() && self.has_rc()
-    }
-
-    fn succ(&mut self) -> Option<&mut Self> {
-        if let Some(mut succ) = self.rc().as_mut() {
-            while let Some(next) = succ.lc().as_mut() {
-                succ = next;
-            }
-            return Some(succ);
-        } else {
-            if self.is_lc() {
-                return unsafe {self.parent().map_or(None, |n| n.as_ptr().as_mut())};
-            } else {
-                if let Some(mut child) = self.parent() {
-                    if unsafe {child.as_ref().is_lc()} {
-                        return unsafe {child.as_ref().parent().map_or(None, |n| n.as_ptr().as_mut())};
-                    }
-                    while let Some(next) = unsafe {child.as_ref().parent()} {
-                        child = next;
-                        if unsafe {child.as_ref().is_lc()} {
-                            return unsafe {child.as_ref().parent().map_or(None, |n| n.as_ptr().as_mut())};
-                        }
-                    }
-                    return None;
-                } else {
-                    return None;
-                }
-            }
-        }
-    }
+#[derive(Debug)]
+pub struct InsertErr(&'static str);
 
-    fn next(&mut self) -> Option<&mut Self> {
-        if let Some(lc) = self.lc().as_mut() {
-            return Some(lc);
-        } else if let Some(rc) = self.rc().as_mut() {
-            return Some(rc);
-        } else {
-            if let Some(mut child) = self.parent() {
-                if self.is_lc() {
-                    return unsafe {child.as_ref().rc().as_mut().map(|b| b.as_mut())};
-                }
-                while let Some(next) = unsafe {child.as_ref().parent()} {
-                    if unsafe {child.as_ref().is_lc()} {
-                        return unsafe {child.as_ref().rc().as_mut().map(|b| b.as_mut())};
-                    }
-                    child = next;
-                }
-                return None;
-            } else {
-                return None;
-            }
-        }
+impl From<AllocErr> for InsertErr {
+    fn from(_: AllocErr) -> Self {
+        InsertErr("allocation failed")
     }
-    
-    fn size_of(subtree: &Self) -> usize {
-        let mut size = 1;
-
-        if let Some(lc) = subtree.lc() {
-            size += Self::size_of(&lc);
-        }
-        if let Some(rc) = subtree.rc() {
-            size += Self::size_of(&rc);
-        }
+}
 
-        size
+impl fmt::Display for InsertErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
     }
 }
 
-#[derive(Debug)]
-pub struct InsertErr(&'static str);
-
 #[derive(Clone, Copy)]
 pub struct Iter<'a, T: 'a, N: 'a + node::Node<T>> {
     ptr: Ptr<N>,
@@ -179,9 +78,9 @@ impl<T> node::Node<T> for BinNode<T> {
         self.rc
     }
 
-    fn new(value: &T, parent: NodePtr<T>) -> Self {
+    fn new(value: T, parent: NodePtr<T>) -> Self {
         BinNode {
-            data: unsafe { ptr::read(value) },
+            data: value,
             parent: parent,
             lc: None,
             rc: None,
@@ -206,7 +105,7 @@ impl<T> node::Node<T> for BinNode<T> {
 
     fn set_lc(&mut self, value: &NodePtr<T>) -> Result<(), InsertErr> {
         unsafe {
-            if let None = self.lc {
+            if self.lc.is_none() {
                 self.lc = ptr::read(value);
                 return Ok(());
             } else {
@@ -217,7 +116,7 @@ impl<T> node::Node<T> for BinNode<T> {
 
     fn set_rc(&mut self, value: &NodePtr<T>) -> Result<(), InsertErr> {
         unsafe {
-            if let None = self.rc {
+            if self.rc.is_none() {
                 self.rc = ptr::read(value);
                 return Ok(());
             } else {
@@ -227,21 +126,33 @@ impl<T> node::Node<T> for BinNode<T> {
     }
 }
 
-pub struct BinTree<T, N: node::Node<T>> {
+pub struct BinTree<T, N: node::Node<T>, A: Allocator = Global> {
     root: Ptr<N>,
     size: usize,
+    alloc: A,
     marker: PhantomData<T>,
 }
 
 impl<T, N: node::Node<T>> BinTree<T, N> {
     pub fn new() -> Self {
+        BinTree::new_in(Global)
+    }
+}
+
+impl<T, N: node::Node<T>, A: Allocator> BinTree<T, N, A> {
+    pub fn new_in(alloc: A) -> Self {
         BinTree {
             root: None,
             size: 0,
+            alloc: alloc,
             marker: PhantomData,
         }
     }
 
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     pub fn root(&self) -> Ptr<N> {
         self.root
     }
@@ -254,21 +165,24 @@ impl<T, N: node::Node<T>> BinTree<T, N> {
         self.size == 0
     }
 
-    pub fn insert_as_root(&mut self, value: &T) {
+    pub fn insert_as_root(&mut self, value: T) -> Result<NonNull<N>, InsertErr> {
+        let root = self.alloc.malloc_val(N::new(value, None))?;
+
         self.size = 1;
-        self.root = NonNull::new(malloc_val(&N::new(value, None)));
+        self.root = NonNull::new(root);
+
+        Ok(self.root.unwrap())
     }
 
     pub fn insert_as_lc(
         &mut self,
         mut ptr: NonNull<N>,
-        value: &T,
+        value: T,
     ) -> Result<NonNull<N>, InsertErr> {
         unsafe {
+            ptr.as_mut().insert_as_lc(value, &self.alloc)?;
             self.size += 1;
 
-            ptr.as_mut().insert_as_lc(value)?;
-
             Ok(ptr.as_mut().lc().unwrap())
         }
     }
@@ -276,13 +190,12 @@ impl<T, N: node::Node<T>> BinTree<T, N> {
     pub fn insert_as_rc(
         &mut self,
         mut ptr: NonNull<N>,
-        value: &T,
+        value: T,
     ) -> Result<NonNull<N>, InsertErr> {
         unsafe {
+            ptr.as_mut().insert_as_rc(value, &self.alloc)?;
             self.size += 1;
 
-            ptr.as_mut().insert_as_rc(value)?;
-
             Ok(ptr.as_mut().rc().unwrap())
         }
     }
@@ -330,11 +243,13 @@ impl<T, N: node::Node<T>> BinTree<T, N> {
         }
 
         let size = self.size();
-        self.size = size - N::remove_at(subtree.as_ptr());
+        self.size = size - N::remove_at(subtree.as_ptr(), &self.alloc);
 
         parent
     }
+}
 
+impl<T, N: node::Node<T>, A: Allocator + Clone> BinTree<T, N, A> {
     pub fn secede(&mut self, mut node: NonNull<N>) -> Self {
         let size = N::size_of(node);
         self.size -= size;
@@ -346,12 +261,13 @@ impl<T, N: node::Node<T>> BinTree<T, N> {
         Self {
             root: Some(node),
             size: size,
+            alloc: self.alloc.clone(),
             marker: PhantomData,
         }
     }
 }
 
-impl<'a, T: 'a, N: 'a + node::Node<T>> BinTree<T, N> {
+impl<'a, T: 'a, N: 'a + node::Node<T>, A: Allocator> BinTree<T, N, A> {
     pub fn iter(&'a mut self) -> Iter<'a, T, N> {
         Iter {
             ptr: self.root,
@@ -360,12 +276,42 @@ impl<'a, T: 'a, N: 'a + node::Node<T>> BinTree<T, N> {
     }
 }
 
-impl<T, N: node::Node<T>> Drop for BinTree<T, N> {
+impl<T: Clone, A: Allocator> BinTree<T, BinNode<T>, A> {
+    fn clone_children(&mut self, src: NonNull<BinNode<T>>, dst: NonNull<BinNode<T>>) {
+        unsafe {
+            if let Some(lc) = src.as_ref().lc {
+                let node = self.insert_as_lc(dst, lc.as_ref().data.clone()).unwrap();
+                self.clone_children(lc, node);
+            }
+            if let Some(rc) = src.as_ref().rc {
+                let node = self.insert_as_rc(dst, rc.as_ref().data.clone()).unwrap();
+                self.clone_children(rc, node);
+            }
+        }
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for BinTree<T, BinNode<T>, A> {
+    fn clone(&self) -> Self {
+        let mut tree = BinTree::new_in(self.alloc.clone());
+
+        if let Some(root) = self.root {
+            unsafe {
+                let node = tree.insert_as_root(root.as_ref().data.clone()).unwrap();
+                tree.clone_children(root, node);
+            }
+        }
+
+        tree
+    }
+}
+
+impl<T, N: node::Node<T>, A: Allocator> Drop for BinTree<T, N, A> {
     fn drop(&mut self) {
         if let Some(root) = self.root {
             unsafe {
-                if root.as_ref().parent() == None {
-                    N::remove_at(root.as_ptr());
+                if root.as_ref().parent().is_none() {
+                    N::remove_at(root.as_ptr(), &self.alloc);
                 }
             }
         }
@@ -1,7 +1,90 @@
-use super::node::Node;
+use super::node::{ColorNode, Node};
+use super::BinNode;
+use super::InsertErr;
+use super::Ptr;
+use std::ptr::NonNull;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Color {
     Red,
     Black,
 }
+
+#[repr(C)]
+pub struct ColorBinNode<T> {
+    node: BinNode<T>,
+    color: Color,
+}
+
+impl<T> ColorBinNode<T> {
+    fn into(node: Ptr<Self>) -> Ptr<BinNode<T>> {
+        if let Some(mut node) = node {
+            return unsafe { NonNull::new(&mut node.as_mut().node) };
+        } else {
+            None
+        }
+    }
+
+    fn from(node: Ptr<BinNode<T>>) -> Ptr<Self> {
+        if let Some(node) = node {
+            return NonNull::new(node.as_ptr() as *mut ColorBinNode<T>);
+        } else {
+            None
+        }
+    }
+
+    pub fn color_of(node: Ptr<Self>) -> Color {
+        if let Some(node) = node {
+            return unsafe { node.as_ref().color() };
+        }
+
+        Color::Black
+    }
+}
+
+impl<T> Node<T> for ColorBinNode<T> {
+    fn get(&mut self) -> &mut T {
+        self.node.get()
+    }
+
+    fn parent(&self) -> Ptr<Self> {
+        Self::from(self.node.parent())
+    }
+
+    fn lc(&self) -> Ptr<Self> {
+        Self::from(self.node.lc())
+    }
+
+    fn rc(&self) -> Ptr<Self> {
+        Self::from(self.node.rc())
+    }
+
+    fn new(value: T, parent: Ptr<Self>) -> Self {
+        Self {
+            node: BinNode::new(value, Self::into(parent)),
+            color: Color::Red,
+        }
+    }
+
+    fn set_parent(&mut self, value: &Ptr<Self>) {
+        self.node.set_parent(&Self::into(*value));
+    }
+
+    fn set_lc(&mut self, value: &Ptr<Self>) -> Result<(), InsertErr> {
+        self.node.set_lc(&Self::into(*value))
+    }
+
+    fn set_rc(&mut self, value: &Ptr<Self>) -> Result<(), InsertErr> {
+        self.node.set_rc(&Self::into(*value))
+    }
+}
+
+impl<T> ColorNode<T> for ColorBinNode<T> {
+    fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    fn color(&self) -> Color {
+        self.color
+    }
+}
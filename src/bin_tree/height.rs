@@ -80,7 +80,7 @@ where
         Self::from(self.node.rc())
     }
 
-    fn new(value: &T, parent: Ptr<Self>) -> Self {
+    fn new(value: T, parent: Ptr<Self>) -> Self {
         Self {
             node: BinNode::new(value, Self::into(parent)),
             height: 1,
@@ -92,7 +92,7 @@ where
 
         self.node.set_parent(&Self::into(*value));
 
-        if parent != None {
+        if parent.is_some() {
             Self::update_height_above(Self::from(parent).unwrap());
         }
     }
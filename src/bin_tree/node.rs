@@ -1,10 +1,10 @@
-use super::super::utility::{free, malloc_val};
+use super::super::utility::Allocator;
 use super::color::Color;
 use super::{InsertErr, Ptr};
 use std::ptr::{self, NonNull};
 
 pub trait Node<T>: Sized {
-    fn new(value: &T, parent: Ptr<Self>) -> Self;
+    fn new(value: T, parent: Ptr<Self>) -> Self;
     fn set_parent(&mut self, value: &Ptr<Self>);
     fn set_lc(&mut self, value: &Ptr<Self>) -> Result<(), InsertErr>;
     fn set_rc(&mut self, value: &Ptr<Self>) -> Result<(), InsertErr>;
@@ -20,7 +20,7 @@ pub trait Node<T>: Sized {
     }
 
     fn is_root(&self) -> bool {
-        self.parent() == None
+        self.parent().is_none()
     }
 
     fn is_lc(&self) -> bool {
@@ -42,11 +42,11 @@ pub trait Node<T>: Sized {
     }
 
     fn has_lc(&self) -> bool {
-        self.lc() != None
+        self.lc().is_some()
     }
 
     fn has_rc(&self) -> bool {
-        self.rc() != None
+        self.rc().is_some()
     }
 
     fn is_leaf(&self) -> bool {
@@ -94,9 +94,9 @@ pub trait Node<T>: Sized {
         let mut succ: Ptr<Self>;
 
         unsafe {
-            if let Some(_) = self.lc() {
+            if self.lc().is_some() {
                 succ = self.lc();
-            } else if let Some(_) = self.rc() {
+            } else if self.rc().is_some() {
                 succ = self.rc();
             } else {
                 succ = None;
@@ -132,41 +132,42 @@ pub trait Node<T>: Sized {
         size
     }
 
-    fn insert_as_lc(&mut self, value: &T) -> Result<(), InsertErr> {
+    fn insert_as_lc<A: Allocator>(&mut self, value: T, alloc: &A) -> Result<(), InsertErr> {
         let parent: *mut Self = self;
+        let node = alloc.malloc_val(Self::new(value, NonNull::new(parent)))?;
 
-        self.set_lc(&NonNull::new(malloc_val(&Self::new(
-            value,
-            NonNull::new(parent),
-        ))))?;
-
-        Ok(())
+        self.set_lc(&NonNull::new(node)).map_err(|err| {
+            unsafe { ptr::drop_in_place(node) };
+            alloc.free(node, 1);
+            err
+        })
     }
 
-    fn insert_as_rc(&mut self, value: &T) -> Result<(), InsertErr> {
+    fn insert_as_rc<A: Allocator>(&mut self, value: T, alloc: &A) -> Result<(), InsertErr> {
         let parent: *mut Self = self;
+        let node = alloc.malloc_val(Self::new(value, NonNull::new(parent)))?;
 
-        self.set_rc(&NonNull::new(malloc_val(&Self::new(
-            value,
-            NonNull::new(parent),
-        ))))?;
-
-        Ok(())
+        self.set_rc(&NonNull::new(node)).map_err(|err| {
+            unsafe { ptr::drop_in_place(node) };
+            alloc.free(node, 1);
+            err
+        })
     }
 
-    fn remove_at(subtree: *mut Self) -> usize {
+    fn remove_at<A: Allocator>(subtree: *mut Self, alloc: &A) -> usize {
         let mut size = 1;
 
         unsafe {
             if let Some(lc) = (*subtree).lc() {
-                size += Self::remove_at(lc.as_ptr());
+                size += Self::remove_at(lc.as_ptr(), alloc);
             }
             if let Some(rc) = (*subtree).rc() {
-                size += Self::remove_at(rc.as_ptr());
+                size += Self::remove_at(rc.as_ptr(), alloc);
             }
         }
 
-        free(subtree, 1).unwrap();
+        unsafe { ptr::drop_in_place(subtree) };
+        alloc.free(subtree, 1);
 
         size
     }
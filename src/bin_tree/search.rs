@@ -1,9 +1,9 @@
+use super::color::{Color, ColorBinNode};
 use super::height::{HeightBinNode, UpdateHeight};
-use super::node::Node;
+use super::node::{ColorNode, Node};
 use super::{BinNode, BinTree, Iter, Ptr};
 use std::cmp::Ordering;
 use std::ptr::NonNull;
-use std::marker::PhantomData;
 
 pub type BST<T> = BinarySearchTree<T, BinNode<T>>;
 type SubTree<T> = BinTree<T, HeightBinNode<T>>;
@@ -15,7 +15,7 @@ pub trait Search<T: Ord, N: Node<T>> {
     where
         F: Fn(K, &T) -> Ordering,
         Self: 'a;
-    fn insert(&mut self, value: &T) -> Ptr<N>;
+    fn insert(&mut self, value: T) -> Ptr<N>;
     fn remove(&mut self, value: &T) -> Ptr<N>;
     fn iter<'a>(&'a mut self) -> Iter<'a, T, N>
     where
@@ -23,54 +23,6 @@ pub trait Search<T: Ord, N: Node<T>> {
         N: 'a;
 }
 
-pub struct BinarySearchTree0<T: Ord, N: super::Node<T>> {
-    root: Option<Box<N>>,
-    size: usize,
-    marker: PhantomData<T>
-}
-
-impl<T: Ord, N: super::Node<T>> BinarySearchTree0<T, N> {
-    fn search_node<'a, K: Copy, F>(&mut self, key: K, cmp: F) -> Option<&'a mut N>
-    where
-        F: Fn(K, &T) -> Ordering,
-        Self: 'a,
-    {
-        let mut node = self.root.as_mut().map(|b| b.as_mut());
-
-        unsafe {
-            while let Some(parent) = node {
-                match cmp(key, parent.get()) {
-                    Ordering::Equal => {
-                        return Some(parent);
-                    }
-                    Ordering::Less => {
-                        node = parent.lc().as_mut().map(|b| b.as_mut());
-                    }
-                    Ordering::Greater => {
-                        node = parent.rc().as_mut().map(|b| b.as_mut());
-                    }
-                }
-            }
-        }
-
-        None
-    }
-}
-
-impl<T: Ord, N: super::Node<T>> BinarySearchTree0<T, N> {
-    fn size(&self) -> usize {
-        self.size
-    }
-
-    fn search<'a, K: Copy, F>(&mut self, key: K, cmp: F) -> Option<&mut T>
-    where
-        F: Fn(K, &T) -> Ordering,
-        Self: 'a,
-    {
-        return self.search_node(key, cmp).map(|node| node.get());
-    }
-}
-
 pub struct BinarySearchTree<T: Ord, N: Node<T>> {
     bin_tree: BinTree<T, N>,
 }
@@ -98,9 +50,9 @@ impl<T: Ord, N: Node<T>> Search<T, N> for BinarySearchTree<T, N> {
         None
     }
 
-    fn insert(&mut self, value: &T) -> Ptr<N> {
+    fn insert(&mut self, value: T) -> Ptr<N> {
         if self.bin_tree.empty() {
-            self.bin_tree.insert_as_root(value);
+            self.bin_tree.insert_as_root(value).unwrap();
             return None;
         }
 
@@ -124,7 +76,7 @@ impl<T: Ord, N: Node<T>> Search<T, N> for BinarySearchTree<T, N> {
                 point = parent;
             }
 
-            if value < point.as_mut().get() {
+            if value < *point.as_mut().get() {
                 return Some(self.bin_tree.insert_as_lc(point, value).unwrap());
             } else {
                 return Some(self.bin_tree.insert_as_rc(point, value).unwrap());
@@ -237,7 +189,7 @@ impl<T: Ord> Search<T, HeightBinNode<T>> for AVLTree<T> {
         self.bst.search(key, cmp)
     }
 
-    fn insert(&mut self, value: &T) -> Ptr<HeightBinNode<T>> {
+    fn insert(&mut self, value: T) -> Ptr<HeightBinNode<T>> {
         unsafe {
             let node = self.bst.insert(value);
             return self.balance(node);
@@ -296,6 +248,7 @@ impl<T: Ord> AVLTree<T> {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn connect34(
         &mut self,
         a: SubTree<T>,
@@ -394,12 +347,16 @@ impl<T: Ord> AVLTree<T> {
                             self.bst
                                 .bin_tree
                                 .attach_as_lc(parent, balanced)
-                                .expect(&format!("{:?} {:?}", parent.as_ref().lc(), Some(node)));
+                                .unwrap_or_else(|_| {
+                                    panic!("{:?} {:?}", parent.as_ref().lc(), Some(node))
+                                });
                         } else {
                             self.bst
                                 .bin_tree
                                 .attach_as_rc(parent, balanced)
-                                .expect(&format!("{:?} {:?}", parent.as_ref().rc(), Some(node)));
+                                .unwrap_or_else(|_| {
+                                    panic!("{:?} {:?}", parent.as_ref().rc(), Some(node))
+                                });
                         }
                     } else {
                         self.bst.bin_tree = balanced;
@@ -415,3 +372,306 @@ impl<T: Ord> AVLTree<T> {
         None
     }
 }
+
+pub struct RedBlackTree<T: Ord> {
+    bst: BinarySearchTree<T, ColorBinNode<T>>,
+}
+
+impl<T: Ord> Search<T, ColorBinNode<T>> for RedBlackTree<T> {
+    fn new() -> Self {
+        Self {
+            bst: BinarySearchTree::new(),
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.bst.size()
+    }
+
+    fn search<'a, K: Copy, F>(&mut self, key: K, cmp: F) -> Option<&'a mut T>
+    where
+        F: Fn(K, &T) -> Ordering,
+        Self: 'a,
+    {
+        self.bst.search(key, cmp)
+    }
+
+    fn insert(&mut self, value: T) -> Ptr<ColorBinNode<T>> {
+        if self.bst.bin_tree.empty() {
+            self.bst.insert(value);
+            let mut root = self.bst.bin_tree.root().unwrap();
+            unsafe {
+                root.as_mut().set_color(Color::Black);
+            }
+            return None;
+        }
+
+        let node = self.bst.insert(value);
+
+        if let Some(node) = node {
+            unsafe {
+                self.solve_double_red(node);
+            }
+        }
+
+        node
+    }
+
+    fn remove(&mut self, value: &T) -> Ptr<ColorBinNode<T>> {
+        let point = self.bst.search_node(value, |a, b| a.cmp(b));
+
+        unsafe {
+            if let Some(mut point) = point {
+                if point.as_ref().has_double_branch() {
+                    let succ = point.as_ref().succ().unwrap();
+                    ColorBinNode::swap(point, succ);
+                    point = succ;
+                }
+
+                let parent = point.as_ref().parent();
+                let child = if point.as_ref().has_lc() {
+                    point.as_ref().lc()
+                } else {
+                    point.as_ref().rc()
+                };
+
+                if let Some(mut child) = child {
+                    let is_lc = point.as_ref().is_lc();
+
+                    child.as_mut().set_parent(&None);
+                    self.bst.bin_tree.remove(point);
+                    self.replace(parent, is_lc, child);
+                    child.as_mut().set_color(Color::Black);
+                } else {
+                    if point.as_ref().color() == Color::Black {
+                        self.solve_double_black(point);
+                    }
+
+                    self.bst.bin_tree.remove(point);
+                    if self.bst.bin_tree.empty() {
+                        self.bst.bin_tree.root = None;
+                    }
+                }
+
+                return parent;
+            }
+
+            None
+        }
+    }
+
+    fn iter<'a>(&'a mut self) -> Iter<'a, T, ColorBinNode<T>>
+    where
+        T: 'a,
+        ColorBinNode<T>: 'a,
+    {
+        self.bst.iter()
+    }
+}
+
+impl<T: Ord> RedBlackTree<T> {
+    pub fn is_red_black(&self) -> bool {
+        match self.bst.bin_tree.root() {
+            Some(root) => unsafe {
+                root.as_ref().parent().is_none()
+                    && root.as_ref().color() == Color::Black
+                    && Self::black_height(root).is_some()
+            },
+            None => true,
+        }
+    }
+
+    unsafe fn black_height(node: NonNull<ColorBinNode<T>>) -> Option<usize> {
+        let red = node.as_ref().color() == Color::Red;
+        let mut heights = [0; 2];
+
+        for (i, child) in [node.as_ref().lc(), node.as_ref().rc()].iter().enumerate() {
+            if let Some(child) = *child {
+                if child.as_ref().parent() != Some(node) {
+                    return None;
+                }
+                if red && child.as_ref().color() == Color::Red {
+                    return None;
+                }
+                heights[i] = Self::black_height(child)?;
+            }
+        }
+
+        if heights[0] != heights[1] {
+            return None;
+        }
+
+        Some(if red { heights[0] } else { heights[0] + 1 })
+    }
+
+    unsafe fn replace(
+        &mut self,
+        parent: Ptr<ColorBinNode<T>>,
+        is_lc: bool,
+        mut node: NonNull<ColorBinNode<T>>,
+    ) {
+        if let Some(mut parent) = parent {
+            if is_lc {
+                parent.as_mut().set_lc(&Some(node)).unwrap();
+            } else {
+                parent.as_mut().set_rc(&Some(node)).unwrap();
+            }
+        } else {
+            self.bst.bin_tree.root = Some(node);
+        }
+
+        node.as_mut().set_parent(&parent);
+    }
+
+    unsafe fn rotate_left(&mut self, mut node: NonNull<ColorBinNode<T>>) {
+        let mut rc = node.as_ref().rc().unwrap();
+        let parent = node.as_ref().parent();
+        let is_lc = node.as_ref().is_lc();
+
+        rc.as_mut().set_parent(&None);
+        if let Some(mut t) = rc.as_ref().lc() {
+            t.as_mut().set_parent(&None);
+            node.as_mut().set_rc(&Some(t)).unwrap();
+            t.as_mut().set_parent(&Some(node));
+        }
+
+        node.as_mut().set_parent(&None);
+        self.replace(parent, is_lc, rc);
+        rc.as_mut().set_lc(&Some(node)).unwrap();
+        node.as_mut().set_parent(&Some(rc));
+    }
+
+    unsafe fn rotate_right(&mut self, mut node: NonNull<ColorBinNode<T>>) {
+        let mut lc = node.as_ref().lc().unwrap();
+        let parent = node.as_ref().parent();
+        let is_lc = node.as_ref().is_lc();
+
+        lc.as_mut().set_parent(&None);
+        if let Some(mut t) = lc.as_ref().rc() {
+            t.as_mut().set_parent(&None);
+            node.as_mut().set_lc(&Some(t)).unwrap();
+            t.as_mut().set_parent(&Some(node));
+        }
+
+        node.as_mut().set_parent(&None);
+        self.replace(parent, is_lc, lc);
+        lc.as_mut().set_rc(&Some(node)).unwrap();
+        node.as_mut().set_parent(&Some(lc));
+    }
+
+    unsafe fn solve_double_red(&mut self, mut node: NonNull<ColorBinNode<T>>) {
+        while let Some(mut parent) = node.as_ref().parent() {
+            if parent.as_ref().color() == Color::Black {
+                break;
+            }
+
+            let mut grand = parent.as_ref().parent().unwrap();
+            let parent_is_lc = parent.as_ref().is_lc();
+            let uncle = if parent_is_lc {
+                grand.as_ref().rc()
+            } else {
+                grand.as_ref().lc()
+            };
+
+            if let (Color::Red, Some(mut uncle)) = (ColorBinNode::color_of(uncle), uncle) {
+                parent.as_mut().set_color(Color::Black);
+                uncle.as_mut().set_color(Color::Black);
+                grand.as_mut().set_color(Color::Red);
+                node = grand;
+                continue;
+            }
+
+            if parent_is_lc {
+                if node.as_ref().is_rc() {
+                    self.rotate_left(parent);
+                    parent = node;
+                }
+                self.rotate_right(grand);
+            } else {
+                if node.as_ref().is_lc() {
+                    self.rotate_right(parent);
+                    parent = node;
+                }
+                self.rotate_left(grand);
+            }
+
+            parent.as_mut().set_color(Color::Black);
+            grand.as_mut().set_color(Color::Red);
+            break;
+        }
+
+        self.bst.bin_tree.root().unwrap().as_mut().set_color(Color::Black);
+    }
+
+    unsafe fn solve_double_black(&mut self, mut node: NonNull<ColorBinNode<T>>) {
+        while let Some(mut parent) = node.as_ref().parent() {
+            if node.as_ref().color() == Color::Red {
+                break;
+            }
+
+            let is_lc = node.as_ref().is_lc();
+            let sibling = |parent: NonNull<ColorBinNode<T>>| {
+                if is_lc {
+                    parent.as_ref().rc().unwrap()
+                } else {
+                    parent.as_ref().lc().unwrap()
+                }
+            };
+            let mut s = sibling(parent);
+
+            if s.as_ref().color() == Color::Red {
+                s.as_mut().set_color(Color::Black);
+                parent.as_mut().set_color(Color::Red);
+                if is_lc {
+                    self.rotate_left(parent);
+                } else {
+                    self.rotate_right(parent);
+                }
+                s = sibling(parent);
+            }
+
+            let (near, far) = if is_lc {
+                (s.as_ref().lc(), s.as_ref().rc())
+            } else {
+                (s.as_ref().rc(), s.as_ref().lc())
+            };
+
+            if ColorBinNode::color_of(near) == Color::Black
+                && ColorBinNode::color_of(far) == Color::Black
+            {
+                s.as_mut().set_color(Color::Red);
+                node = parent;
+                continue;
+            }
+
+            if ColorBinNode::color_of(far) == Color::Black {
+                near.unwrap().as_mut().set_color(Color::Black);
+                s.as_mut().set_color(Color::Red);
+                if is_lc {
+                    self.rotate_right(s);
+                } else {
+                    self.rotate_left(s);
+                }
+                s = sibling(parent);
+            }
+
+            let far = if is_lc {
+                s.as_ref().rc()
+            } else {
+                s.as_ref().lc()
+            };
+
+            s.as_mut().set_color(parent.as_ref().color());
+            parent.as_mut().set_color(Color::Black);
+            far.unwrap().as_mut().set_color(Color::Black);
+            if is_lc {
+                self.rotate_left(parent);
+            } else {
+                self.rotate_right(parent);
+            }
+            return;
+        }
+
+        node.as_mut().set_color(Color::Black);
+    }
+}
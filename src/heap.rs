@@ -1,75 +1,354 @@
-pub use super::queue::PriorityQueue;
+pub mod binomial;
+pub mod fibonacci;
+pub mod indexed;
+pub mod leftist;
+pub mod min_max;
+mod node;
+pub mod pairing;
+pub mod skew;
+
+pub use self::node::Handle;
+pub use super::queue::{DoubleEndedPriorityQueue, PriorityQueue};
+use super::stack::Stack;
 use super::vector::Vector;
-use std::ptr;
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut, Drop};
 
-pub struct Heap<T: Ord> {
-    vec: Vector<T>,
+pub trait Sift {
+    fn len(&self) -> usize;
+    fn less(&self, i: usize, j: usize) -> bool;
+    fn swap(&mut self, i: usize, j: usize);
 }
 
-impl<T> Heap<T>
+struct SliceSift<'a, T: 'a, F: 'a> {
+    data: &'a mut [T],
+    less: &'a F,
+}
+
+impl<'a, T: 'a, F: 'a> Sift for SliceSift<'a, T, F>
 where
-    T: Ord + Default,
+    F: Fn(&T, &T) -> bool,
 {
-    pub fn new() -> Self {
-        let mut h = Heap { vec: Vector::new() };
+    fn len(&self) -> usize {
+        self.data.len()
+    }
 
-        h.vec.insert(0, &Default::default());
+    fn less(&self, i: usize, j: usize) -> bool {
+        (self.less)(&self.data[i], &self.data[j])
+    }
 
-        h
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
     }
 }
 
+pub fn sift_up<S: Sift>(data: &mut S, mut index: usize) -> usize {
+    while index != 0 {
+        let parent = (index - 1) / 2;
+
+        if data.less(parent, index) {
+            data.swap(index, parent);
+            index = parent;
+        } else {
+            break;
+        }
+    }
+
+    index
+}
+
+pub fn sift_down<S: Sift>(data: &mut S, mut index: usize) -> usize {
+    let len = data.len();
+
+    while index * 2 + 1 < len {
+        let mut max_child = index * 2 + 1;
+        if max_child + 1 < len && data.less(max_child, max_child + 1) {
+            max_child += 1;
+        }
+
+        if data.less(index, max_child) {
+            data.swap(index, max_child);
+            index = max_child;
+        } else {
+            break;
+        }
+    }
+
+    index
+}
+
+pub fn swim<T, F>(data: &mut [T], index: usize, less: &F)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    sift_up(
+        &mut SliceSift {
+            data: data,
+            less: less,
+        },
+        index,
+    );
+}
+
+pub fn sink<T, F>(data: &mut [T], index: usize, less: &F)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    sift_down(
+        &mut SliceSift {
+            data: data,
+            less: less,
+        },
+        index,
+    );
+}
+
+pub fn heapify<T, F>(data: &mut [T], less: &F)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    for i in (0..data.len() / 2).rev() {
+        sink(data, i, less);
+    }
+}
+
+pub fn sort_heap<T, F>(data: &mut [T], less: &F)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    for end in (1..data.len()).rev() {
+        data.swap(0, end);
+        sink(&mut data[..end], 0, less);
+    }
+}
+
+pub trait Compare<T> {
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+
+    fn less(&self, a: &T, b: &T) -> bool {
+        self.compare(a, b) == Ordering::Less
+    }
+}
+
+impl<T, F> Compare<T> for F
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct MaxComparator;
+
+impl<T: Ord> Compare<T> for MaxComparator {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct MinComparator;
+
+impl<T: Ord> Compare<T> for MinComparator {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ByKey<F>(F);
+
+impl<T, K, F> Compare<T> for ByKey<F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a).cmp(&(self.0)(b))
+    }
+}
+
+pub struct Heap<T, C: Compare<T> = MaxComparator> {
+    vec: Vector<T>,
+    cmp: C,
+}
+
+pub type MinHeap<T> = Heap<T, MinComparator>;
+
 impl<T: Ord> Heap<T> {
+    pub fn new() -> Self {
+        Heap::with_comparator(MaxComparator)
+    }
+
+    pub fn from_vector(vec: Vector<T>) -> Self {
+        Heap::from_vector_with(vec, MaxComparator)
+    }
+}
+
+impl<T: Ord> Heap<T, MinComparator> {
+    pub fn new_min() -> Self {
+        Heap::with_comparator(MinComparator)
+    }
+}
+
+impl<T, K, F> Heap<T, ByKey<F>>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    pub fn by_key(key: F) -> Self {
+        Heap::with_comparator(ByKey(key))
+    }
+}
+
+impl<T, C: Compare<T>> Heap<T, C> {
+    pub fn with_comparator(cmp: C) -> Self {
+        Heap {
+            vec: Vector::new(),
+            cmp: cmp,
+        }
+    }
+
+    pub fn from_vector_with(mut vec: Vector<T>, cmp: C) -> Self {
+        heapify(&mut vec, &|a, b| cmp.less(a, b));
+
+        Heap { vec: vec, cmp: cmp }
+    }
+
+    pub fn into_sorted_vector(mut self) -> Vector<T> {
+        let cmp = &self.cmp;
+        sort_heap(&mut self.vec, &|a, b| cmp.less(a, b));
+
+        self.vec
+    }
+
+    pub fn comparator(&self) -> &C {
+        &self.cmp
+    }
+
     pub fn size(&self) -> usize {
-        self.vec.len() - 1
+        self.vec.len()
     }
 
-    fn swim(&mut self, mut index: usize) {
-        while index != 1 {
-            if self.vec[index] > self.vec[index / 2] {
-                self.vec.swap(index, index / 2);
-                index /= 2;
-            } else {
-                break;
-            }
+    pub fn empty(&self) -> bool {
+        self.vec.empty()
+    }
+
+    pub fn peek_mut<'a>(&'a mut self) -> Option<PeekMut<'a, T, C>> {
+        if self.empty() {
+            return None;
         }
+
+        Some(PeekMut { heap: self })
     }
 
-    fn sink(&mut self, mut index: usize) {
-        while index <= (self.vec.len() - 1) / 2 {
-            let mut max_child = index * 2;
-            if max_child < self.vec.len() - 1 && self.vec[max_child] < self.vec[max_child + 1] {
-                max_child += 1;
-            }
+    pub fn append(&mut self, other: &mut Heap<T, C>) {
+        if self.size() < other.size() {
+            mem::swap(&mut self.vec, &mut other.vec);
+        }
 
-            if self.vec[index] < self.vec[max_child] {
-                self.vec.swap(index, max_child);
-                index = max_child;
-            } else {
-                break;
+        let n = self.size();
+        let m = other.size();
+        let log = mem::size_of::<usize>() * 8 - n.leading_zeros() as usize;
+        let vec = mem::replace(&mut other.vec, Vector::new());
+
+        if m * log > n + m {
+            for value in vec {
+                self.vec.push(value);
+            }
+            let cmp = &self.cmp;
+            heapify(&mut self.vec, &|a, b| cmp.less(a, b));
+        } else {
+            for value in vec {
+                self.insert(value);
             }
         }
     }
+
+    pub fn merge(mut self, mut other: Heap<T, C>) -> Self {
+        self.append(&mut other);
+
+        self
+    }
+
+    fn swim(&mut self, index: usize) {
+        let cmp = &self.cmp;
+        swim(&mut self.vec, index, &|a, b| cmp.less(a, b));
+    }
+
+    fn sink(&mut self, index: usize) {
+        let cmp = &self.cmp;
+        sink(&mut self.vec, index, &|a, b| cmp.less(a, b));
+    }
+}
+
+impl<T, C: Compare<T> + Default> Default for Heap<T, C> {
+    fn default() -> Self {
+        Heap::with_comparator(Default::default())
+    }
 }
 
-impl<T: Ord> PriorityQueue<T> for Heap<T> {
-    fn insert(&mut self, value: &T) {
+impl<T, C: Compare<T>> PriorityQueue<T> for Heap<T, C> {
+    fn insert(&mut self, value: T) {
         let len = self.vec.len();
-        self.vec.insert(len, value);
+        self.vec.push(value);
         self.swim(len);
     }
 
     fn del_max(&mut self) -> T {
+        if self.empty() {
+            panic!("this heap is empty");
+        }
+
         let len = self.vec.len();
-        let v = unsafe { ptr::read(&self.vec[1]) };
-        self.vec.swap(1, len - 1);
-        self.vec.remove(len - 1, len);
-        self.sink(1);
+        self.vec.swap(0, len - 1);
+        let v = self.vec.pop();
+        self.sink(0);
 
         v
     }
 
     fn max(&self) -> &T {
-        &self.vec[1]
+        if self.empty() {
+            panic!("this heap is empty");
+        }
+
+        &self.vec[0]
+    }
+}
+
+pub struct PeekMut<'a, T: 'a, C: 'a + Compare<T>> {
+    heap: &'a mut Heap<T, C>,
+}
+
+impl<'a, T: 'a, C: 'a + Compare<T>> PeekMut<'a, T, C> {
+    pub fn pop(this: PeekMut<'a, T, C>) -> T {
+        let value = this.heap.del_max();
+        mem::forget(this);
+
+        value
+    }
+}
+
+impl<'a, T: 'a, C: 'a + Compare<T>> Deref for PeekMut<'a, T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.heap.vec[0]
+    }
+}
+
+impl<'a, T: 'a, C: 'a + Compare<T>> DerefMut for PeekMut<'a, T, C> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.heap.vec[0]
+    }
+}
+
+impl<'a, T: 'a, C: 'a + Compare<T>> Drop for PeekMut<'a, T, C> {
+    fn drop(&mut self) {
+        self.heap.sink(0);
     }
 }
@@ -0,0 +1,200 @@
+use super::super::utility::{Allocator, Global};
+use super::node::{self, Handle, Link, Meld, Node, NodeHeap};
+use super::{Compare, MaxComparator};
+use std::ptr::NonNull;
+
+pub struct Binomial;
+
+pub type BinomialHeap<T, C = MaxComparator, A = Global> = NodeHeap<T, Binomial, C, A>;
+
+fn less<T, C: Compare<T>>(cmp: &C, a: NonNull<Node<T>>, b: NonNull<Node<T>>) -> bool {
+    unsafe { cmp.less(a.as_ref().data(), b.as_ref().data()) }
+}
+
+fn link<T>(child: NonNull<Node<T>>, mut parent: NonNull<Node<T>>) {
+    unsafe {
+        let node = &mut *child.as_ptr();
+        node.parent = Some(parent);
+        node.right = parent.as_ref().child;
+        parent.as_mut().child = Some(child);
+        parent.as_mut().degree += 1;
+    }
+}
+
+fn merge_lists<T>(mut a: Link<T>, mut b: Link<T>) -> Link<T> {
+    let mut head = None;
+    let mut slot = &mut head as *mut Link<T>;
+
+    unsafe {
+        loop {
+            let (x, y) = match (a, b) {
+                (None, b) => {
+                    *slot = b;
+                    break;
+                }
+                (a, None) => {
+                    *slot = a;
+                    break;
+                }
+                (Some(x), Some(y)) => (x, y),
+            };
+
+            let mut next = if x.as_ref().degree <= y.as_ref().degree {
+                a = x.as_ref().right;
+                x
+            } else {
+                b = y.as_ref().right;
+                y
+            };
+            *slot = Some(next);
+            slot = &mut next.as_mut().right;
+        }
+    }
+
+    head
+}
+
+impl<T> Meld<T> for Binomial {
+    type Node = Node<T>;
+
+    fn alloc<A: Allocator>(value: T, alloc: &A) -> NonNull<Node<T>> {
+        Node::alloc(value, alloc)
+    }
+
+    fn free<A: Allocator>(node: NonNull<Node<T>>, alloc: &A) -> T {
+        Node::free(node, alloc)
+    }
+
+    fn data(node: &Node<T>) -> &T {
+        node.data()
+    }
+
+    fn merge<C: Compare<T>>(cmp: &C, a: Link<T>, b: Link<T>) -> Link<T> {
+        let mut head = merge_lists(a, b);
+        let mut prev: Link<T> = None;
+        let mut x = head?;
+
+        unsafe {
+            while let Some(next) = x.as_ref().right {
+                let skip = next
+                    .as_ref()
+                    .right
+                    .map_or(false, |n| n.as_ref().degree == x.as_ref().degree);
+
+                if x.as_ref().degree != next.as_ref().degree || skip {
+                    prev = Some(x);
+                    x = next;
+                } else if !less(cmp, x, next) {
+                    x.as_mut().right = next.as_ref().right;
+                    link(next, x);
+                } else {
+                    match prev {
+                        Some(mut prev) => prev.as_mut().right = Some(next),
+                        None => head = Some(next),
+                    }
+                    link(x, next);
+                    x = next;
+                }
+            }
+        }
+
+        head
+    }
+
+    fn top<C: Compare<T>>(cmp: &C, head: NonNull<Node<T>>) -> NonNull<Node<T>> {
+        let mut best = head;
+        let mut it = head;
+
+        unsafe {
+            while let Some(node) = it.as_ref().right {
+                if less(cmp, best, node) {
+                    best = node;
+                }
+                it = node;
+            }
+        }
+
+        best
+    }
+
+    fn remove<C: Compare<T>>(cmp: &C, head: NonNull<Node<T>>, root: NonNull<Node<T>>) -> Link<T> {
+        unsafe {
+            let mut head = Some(head);
+            let mut prev = None;
+            let mut it = head;
+
+            while let Some(node) = it {
+                if node == root {
+                    break;
+                }
+                prev = it;
+                it = node.as_ref().right;
+            }
+
+            match prev {
+                Some(mut prev) => prev.as_mut().right = root.as_ref().right,
+                None => head = root.as_ref().right,
+            }
+
+            let mut children = None;
+            let mut it = root.as_ref().child;
+            while let Some(mut child) = it {
+                it = child.as_ref().right;
+                child.as_mut().parent = None;
+                child.as_mut().right = children;
+                children = Some(child);
+            }
+
+            Binomial::merge(cmp, head, children)
+        }
+    }
+
+    fn split(node: NonNull<Node<T>>) -> (Link<T>, Link<T>) {
+        unsafe { (node.as_ref().child, node.as_ref().right) }
+    }
+}
+
+impl<T, C: Compare<T>, A: Allocator> BinomialHeap<T, C, A> {
+    /// Replaces the value behind `handle` with one that ranks at least as
+    /// high under the heap's comparator: a larger value by default, a smaller
+    /// one under `MinComparator`. Panics if the new value ranks lower.
+    ///
+    /// # Safety
+    ///
+    /// Same contract as `get`.
+    pub unsafe fn increase_priority(&mut self, handle: Handle<T>, value: T) {
+        let node = node::node(handle);
+
+        if self.cmp.less(&value, node.as_ref().data()) {
+            panic!("new key ranks below the current key");
+        }
+        (*node.as_ref().item.as_ptr()).data = value;
+        self.bubble_up(node, false);
+    }
+
+    /// # Safety
+    ///
+    /// Same contract as `get`; the handle is dead once this returns.
+    pub unsafe fn delete(&mut self, handle: Handle<T>) -> T {
+        let root = self.bubble_up(node::node(handle), true);
+
+        self.root = Binomial::remove(&self.cmp, self.root.unwrap(), root);
+        self.size -= 1;
+
+        Node::free(root, &self.alloc)
+    }
+
+    fn bubble_up(&self, mut node: NonNull<Node<T>>, force: bool) -> NonNull<Node<T>> {
+        unsafe {
+            while let Some(parent) = node.as_ref().parent {
+                if !force && !less(&self.cmp, parent, node) {
+                    break;
+                }
+                Node::swap_items(node, parent);
+                node = parent;
+            }
+        }
+
+        node
+    }
+}
@@ -0,0 +1,249 @@
+use super::super::utility::{Allocator, Global};
+use super::node::{self, Handle, Link, Meld, Node, NodeHeap};
+use super::{Compare, MaxComparator, PriorityQueue};
+use std::mem;
+use std::ptr::NonNull;
+
+pub struct Fibonacci;
+
+pub type FibonacciHeap<T, C = MaxComparator, A = Global> = NodeHeap<T, Fibonacci, C, A>;
+
+fn less<T, C: Compare<T>>(cmp: &C, a: NonNull<Node<T>>, b: NonNull<Node<T>>) -> bool {
+    unsafe { cmp.less(a.as_ref().data(), b.as_ref().data()) }
+}
+
+fn splice<T>(mut a: NonNull<Node<T>>, mut b: NonNull<Node<T>>) {
+    unsafe {
+        let mut a_right = a.as_ref().right.unwrap();
+        let mut b_left = b.as_ref().left.unwrap();
+
+        a.as_mut().right = Some(b);
+        b.as_mut().left = Some(a);
+        b_left.as_mut().right = Some(a_right);
+        a_right.as_mut().left = Some(b_left);
+    }
+}
+
+fn unlink<T>(mut node: NonNull<Node<T>>) {
+    unsafe {
+        let mut left = node.as_ref().left.unwrap();
+        let mut right = node.as_ref().right.unwrap();
+
+        left.as_mut().right = Some(right);
+        right.as_mut().left = Some(left);
+        node.as_mut().left = Some(node);
+        node.as_mut().right = Some(node);
+    }
+}
+
+fn add_root<T, C: Compare<T>>(cmp: &C, top: Link<T>, mut node: NonNull<Node<T>>) -> Link<T> {
+    unsafe {
+        node.as_mut().parent = None;
+        node.as_mut().mark = false;
+    }
+
+    Fibonacci::merge(cmp, top, Some(node))
+}
+
+fn consolidate<T, C: Compare<T>>(cmp: &C, start: NonNull<Node<T>>) -> Link<T> {
+    let mut roots = Vec::new();
+    let mut it = start;
+
+    unsafe {
+        loop {
+            roots.push(it);
+            it = it.as_ref().right.unwrap();
+            if it == start {
+                break;
+            }
+        }
+    }
+
+    let mut table: Vec<Link<T>> = Vec::new();
+    for root in roots {
+        let mut x = root;
+
+        unlink(x);
+        unsafe {
+            loop {
+                let d = x.as_ref().degree;
+                if table.len() <= d {
+                    table.resize(d + 1, None);
+                }
+
+                let mut y = match table[d].take() {
+                    Some(y) => y,
+                    None => {
+                        table[d] = Some(x);
+                        break;
+                    }
+                };
+                if less(cmp, x, y) {
+                    mem::swap(&mut x, &mut y);
+                }
+
+                y.as_mut().parent = Some(x);
+                y.as_mut().mark = false;
+                match x.as_ref().child {
+                    Some(child) => splice(child, y),
+                    None => x.as_mut().child = Some(y),
+                }
+                x.as_mut().degree += 1;
+            }
+        }
+    }
+
+    let mut top = None;
+    for root in table.into_iter().flatten() {
+        top = add_root(cmp, top, root);
+    }
+
+    top
+}
+
+impl<T> Meld<T> for Fibonacci {
+    type Node = Node<T>;
+
+    fn alloc<A: Allocator>(value: T, alloc: &A) -> NonNull<Node<T>> {
+        let mut node = Node::alloc(value, alloc);
+
+        unsafe {
+            node.as_mut().left = Some(node);
+            node.as_mut().right = Some(node);
+        }
+
+        node
+    }
+
+    fn free<A: Allocator>(node: NonNull<Node<T>>, alloc: &A) -> T {
+        Node::free(node, alloc)
+    }
+
+    fn data(node: &Node<T>) -> &T {
+        node.data()
+    }
+
+    fn merge<C: Compare<T>>(cmp: &C, a: Link<T>, b: Link<T>) -> Link<T> {
+        match (a, b) {
+            (None, b) => b,
+            (a, None) => a,
+            (Some(a), Some(b)) => {
+                splice(a, b);
+                if less(cmp, a, b) {
+                    Some(b)
+                } else {
+                    Some(a)
+                }
+            }
+        }
+    }
+
+    fn remove<C: Compare<T>>(cmp: &C, _: NonNull<Node<T>>, top: NonNull<Node<T>>) -> Link<T> {
+        unsafe {
+            let mut next = top.as_ref().right.unwrap();
+
+            if let Some(child) = top.as_ref().child {
+                let mut it = child;
+                loop {
+                    it.as_mut().parent = None;
+                    it = it.as_ref().right.unwrap();
+                    if it == child {
+                        break;
+                    }
+                }
+                splice(top, child);
+                if next == top {
+                    next = child;
+                }
+            }
+
+            unlink(top);
+            if next == top {
+                None
+            } else {
+                consolidate(cmp, next)
+            }
+        }
+    }
+
+    fn split(node: NonNull<Node<T>>) -> (Link<T>, Link<T>) {
+        unsafe {
+            let right = node.as_ref().right.unwrap();
+
+            if right == node {
+                (node.as_ref().child, None)
+            } else {
+                unlink(node);
+                (node.as_ref().child, Some(right))
+            }
+        }
+    }
+}
+
+impl<T, C: Compare<T>, A: Allocator> FibonacciHeap<T, C, A> {
+    /// Replaces the value behind `handle` with one that ranks at least as
+    /// high under the heap's comparator: a larger value by default, a smaller
+    /// one under `MinComparator`. Panics if the new value ranks lower.
+    ///
+    /// # Safety
+    ///
+    /// Same contract as `get`.
+    pub unsafe fn increase_priority(&mut self, handle: Handle<T>, value: T) {
+        let node = node::node(handle);
+
+        if self.cmp.less(&value, node.as_ref().data()) {
+            panic!("new key ranks below the current key");
+        }
+        (*node.as_ref().item.as_ptr()).data = value;
+
+        if let Some(parent) = node.as_ref().parent {
+            if less(&self.cmp, parent, node) {
+                self.cut(node, parent);
+            }
+        }
+        if less(&self.cmp, self.root.unwrap(), node) {
+            self.root = Some(node);
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Same contract as `get`; the handle is dead once this returns.
+    pub unsafe fn delete(&mut self, handle: Handle<T>) -> T {
+        let node = node::node(handle);
+
+        if let Some(parent) = node.as_ref().parent {
+            self.cut(node, parent);
+        }
+        self.root = Some(node);
+
+        self.del_max()
+    }
+
+    fn cut(&mut self, mut node: NonNull<Node<T>>, mut parent: NonNull<Node<T>>) {
+        unsafe {
+            loop {
+                let right = node.as_ref().right;
+
+                parent.as_mut().degree -= 1;
+                if parent.as_ref().child == Some(node) {
+                    parent.as_mut().child = if right == Some(node) { None } else { right };
+                }
+                unlink(node);
+                self.root = add_root(&self.cmp, self.root, node);
+
+                match parent.as_ref().parent {
+                    Some(grand) if parent.as_ref().mark => {
+                        node = parent;
+                        parent = grand;
+                    }
+                    Some(_) => {
+                        parent.as_mut().mark = true;
+                        break;
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::super::stack::Stack;
+use super::super::vector::Vector;
+use super::{sift_down, sift_up, Compare, MaxComparator, PriorityQueue, Sift};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem;
+
+struct Entries<'a, K: 'a, P: 'a, C: 'a> {
+    entries: &'a mut Vector<(K, P)>,
+    index: &'a mut HashMap<K, usize>,
+    cmp: &'a C,
+}
+
+impl<'a, K, P, C> Sift for Entries<'a, K, P, C>
+where
+    K: 'a + Hash + Eq,
+    P: 'a,
+    C: 'a + Compare<P>,
+{
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn less(&self, i: usize, j: usize) -> bool {
+        self.cmp.less(&self.entries[i].1, &self.entries[j].1)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.entries.swap(i, j);
+        *self.index.get_mut(&self.entries[i].0).unwrap() = i;
+        *self.index.get_mut(&self.entries[j].0).unwrap() = j;
+    }
+}
+
+pub struct IndexedHeap<K, P, C: Compare<P> = MaxComparator> {
+    entries: Vector<(K, P)>,
+    index: HashMap<K, usize>,
+    cmp: C,
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> IndexedHeap<K, P> {
+    pub fn new() -> Self {
+        IndexedHeap::with_comparator(MaxComparator)
+    }
+}
+
+impl<K: Hash + Eq + Clone, P, C: Compare<P>> IndexedHeap<K, P, C> {
+    pub fn with_comparator(cmp: C) -> Self {
+        IndexedHeap {
+            entries: Vector::new(),
+            index: HashMap::new(),
+            cmp: cmp,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn empty(&self) -> bool {
+        self.entries.empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.index.get(key).map(|&i| &self.entries[i].1)
+    }
+
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.entries.get(0).map(|entry| (&entry.0, &entry.1))
+    }
+
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        if self.contains(&key) {
+            return self.change_priority(&key, priority);
+        }
+
+        let len = self.entries.len();
+        self.index.insert(key.clone(), len);
+        self.entries.push((key, priority));
+        sift_up(&mut self.sift(), len);
+
+        None
+    }
+
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.empty() {
+            return None;
+        }
+
+        let entry = self.take(0);
+        sift_down(&mut self.sift(), 0);
+
+        Some(entry)
+    }
+
+    pub fn change_priority(&mut self, key: &K, priority: P) -> Option<P> {
+        let i = match self.index.get(key) {
+            Some(&i) => i,
+            None => return None,
+        };
+        let old = mem::replace(&mut self.entries[i].1, priority);
+
+        let mut sift = self.sift();
+        let i = sift_up(&mut sift, i);
+        sift_down(&mut sift, i);
+
+        Some(old)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<P> {
+        let i = match self.index.get(key) {
+            Some(&i) => i,
+            None => return None,
+        };
+        let (_, priority) = self.take(i);
+
+        if i < self.entries.len() {
+            let mut sift = self.sift();
+            let i = sift_up(&mut sift, i);
+            sift_down(&mut sift, i);
+        }
+
+        Some(priority)
+    }
+
+    fn take(&mut self, i: usize) -> (K, P) {
+        let last = self.entries.len() - 1;
+
+        self.sift().swap(i, last);
+        let entry = self.entries.pop();
+        self.index.remove(&entry.0);
+
+        entry
+    }
+
+    fn sift<'a>(&'a mut self) -> Entries<'a, K, P, C> {
+        Entries {
+            entries: &mut self.entries,
+            index: &mut self.index,
+            cmp: &self.cmp,
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, P, C: Compare<P> + Default> Default for IndexedHeap<K, P, C> {
+    fn default() -> Self {
+        IndexedHeap::with_comparator(Default::default())
+    }
+}
+
+impl<K: Hash + Eq + Clone, P, C: Compare<P>> PriorityQueue<(K, P)> for IndexedHeap<K, P, C> {
+    fn insert(&mut self, value: (K, P)) {
+        self.push(value.0, value.1);
+    }
+
+    fn del_max(&mut self) -> (K, P) {
+        self.pop().expect("this heap is empty")
+    }
+
+    fn max(&self) -> &(K, P) {
+        self.entries.get(0).expect("this heap is empty")
+    }
+}
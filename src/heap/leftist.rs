@@ -0,0 +1,80 @@
+use super::super::utility::{Allocator, Global};
+use super::node::{self, Meld, NodeHeap, Ptr};
+use super::{Compare, MaxComparator};
+use std::mem;
+use std::ptr::NonNull;
+
+type Link<T> = Ptr<Node<T>>;
+
+pub struct Node<T> {
+    data: T,
+    lc: Link<T>,
+    rc: Link<T>,
+    npl: usize,
+}
+
+fn npl<T>(node: Link<T>) -> usize {
+    match node {
+        Some(node) => unsafe { node.as_ref().npl },
+        None => 0,
+    }
+}
+
+pub struct Leftist;
+
+pub type LeftistHeap<T, C = MaxComparator, A = Global> = NodeHeap<T, Leftist, C, A>;
+
+impl<T> Meld<T> for Leftist {
+    type Node = Node<T>;
+
+    fn alloc<A: Allocator>(value: T, alloc: &A) -> NonNull<Node<T>> {
+        node::alloc(
+            Node {
+                data: value,
+                lc: None,
+                rc: None,
+                npl: 1,
+            },
+            alloc,
+        )
+    }
+
+    fn free<A: Allocator>(node: NonNull<Node<T>>, alloc: &A) -> T {
+        node::free(node, alloc).data
+    }
+
+    fn data(node: &Node<T>) -> &T {
+        &node.data
+    }
+
+    fn merge<C: Compare<T>>(cmp: &C, a: Link<T>, b: Link<T>) -> Link<T> {
+        let (mut a, mut b) = match (a, b) {
+            (None, b) => return b,
+            (a, None) => return a,
+            (Some(a), Some(b)) => (a, b),
+        };
+
+        unsafe {
+            if cmp.less(&a.as_ref().data, &b.as_ref().data) {
+                mem::swap(&mut a, &mut b);
+            }
+
+            let node = a.as_mut();
+            node.rc = Leftist::merge(cmp, node.rc, Some(b));
+            if npl(node.lc) < npl(node.rc) {
+                mem::swap(&mut node.lc, &mut node.rc);
+            }
+            node.npl = npl(node.rc) + 1;
+        }
+
+        Some(a)
+    }
+
+    fn remove<C: Compare<T>>(cmp: &C, root: NonNull<Node<T>>, _: NonNull<Node<T>>) -> Link<T> {
+        unsafe { Leftist::merge(cmp, root.as_ref().lc, root.as_ref().rc) }
+    }
+
+    fn split(node: NonNull<Node<T>>) -> (Link<T>, Link<T>) {
+        unsafe { (node.as_ref().lc, node.as_ref().rc) }
+    }
+}
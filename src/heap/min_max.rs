@@ -0,0 +1,193 @@
+use super::super::queue::DoubleEndedPriorityQueue;
+use super::super::stack::Stack;
+use super::super::vector::Vector;
+use super::{Compare, MaxComparator, PriorityQueue};
+use std::mem;
+
+fn max_level(index: usize) -> bool {
+    let depth = mem::size_of::<usize>() * 8 - 1 - (index + 1).leading_zeros() as usize;
+
+    depth % 2 == 1
+}
+
+pub struct MinMaxHeap<T, C: Compare<T> = MaxComparator> {
+    vec: Vector<T>,
+    cmp: C,
+}
+
+impl<T: Ord> MinMaxHeap<T> {
+    pub fn new() -> Self {
+        MinMaxHeap::with_comparator(MaxComparator)
+    }
+
+    pub fn from_vector(vec: Vector<T>) -> Self {
+        MinMaxHeap::from_vector_with(vec, MaxComparator)
+    }
+}
+
+impl<T, C: Compare<T>> MinMaxHeap<T, C> {
+    pub fn with_comparator(cmp: C) -> Self {
+        MinMaxHeap {
+            vec: Vector::new(),
+            cmp: cmp,
+        }
+    }
+
+    pub fn from_vector_with(vec: Vector<T>, cmp: C) -> Self {
+        let mut heap = MinMaxHeap { vec: vec, cmp: cmp };
+
+        for i in (0..heap.vec.len() / 2).rev() {
+            heap.trickle_down(i);
+        }
+
+        heap
+    }
+
+    pub fn size(&self) -> usize {
+        self.vec.len()
+    }
+
+    pub fn empty(&self) -> bool {
+        self.vec.empty()
+    }
+
+    fn better(&self, i: usize, j: usize, max: bool) -> bool {
+        if max {
+            self.cmp.less(&self.vec[j], &self.vec[i])
+        } else {
+            self.cmp.less(&self.vec[i], &self.vec[j])
+        }
+    }
+
+    fn max_index(&self) -> usize {
+        match self.vec.len() {
+            0 | 1 => 0,
+            2 => 1,
+            _ => {
+                if self.better(2, 1, true) {
+                    2
+                } else {
+                    1
+                }
+            }
+        }
+    }
+
+    fn bubble_up(&mut self, mut index: usize) {
+        if index == 0 {
+            return;
+        }
+
+        let mut max = max_level(index);
+        let parent = (index - 1) / 2;
+
+        if self.better(index, parent, !max) {
+            self.vec.swap(index, parent);
+            index = parent;
+            max = !max;
+        }
+
+        while index > 2 {
+            let grand = ((index - 1) / 2 - 1) / 2;
+
+            if !self.better(index, grand, max) {
+                break;
+            }
+            self.vec.swap(index, grand);
+            index = grand;
+        }
+    }
+
+    fn trickle_down(&mut self, mut index: usize) {
+        let max = max_level(index);
+        let len = self.vec.len();
+
+        loop {
+            let child = index * 2 + 1;
+            if child >= len {
+                break;
+            }
+
+            let mut m = child;
+            let candidates = [
+                child + 1,
+                child * 2 + 1,
+                child * 2 + 2,
+                child * 2 + 3,
+                child * 2 + 4,
+            ];
+            for &i in candidates.iter() {
+                if i < len && self.better(i, m, max) {
+                    m = i;
+                }
+            }
+
+            if !self.better(m, index, max) {
+                break;
+            }
+            self.vec.swap(m, index);
+            if m <= child + 1 {
+                break;
+            }
+
+            let parent = (m - 1) / 2;
+            if self.better(m, parent, !max) {
+                self.vec.swap(m, parent);
+            }
+            index = m;
+        }
+    }
+
+    fn take(&mut self, index: usize) -> T {
+        let last = self.vec.len() - 1;
+
+        self.vec.swap(index, last);
+        let value = self.vec.pop();
+        if index < last {
+            self.trickle_down(index);
+        }
+
+        value
+    }
+}
+
+impl<T, C: Compare<T> + Default> Default for MinMaxHeap<T, C> {
+    fn default() -> Self {
+        MinMaxHeap::with_comparator(Default::default())
+    }
+}
+
+impl<T, C: Compare<T>> PriorityQueue<T> for MinMaxHeap<T, C> {
+    fn insert(&mut self, value: T) {
+        let len = self.vec.len();
+        self.vec.push(value);
+        self.bubble_up(len);
+    }
+
+    fn del_max(&mut self) -> T {
+        if self.empty() {
+            panic!("this heap is empty");
+        }
+
+        let index = self.max_index();
+        self.take(index)
+    }
+
+    fn max(&self) -> &T {
+        &self.vec[self.max_index()]
+    }
+}
+
+impl<T, C: Compare<T>> DoubleEndedPriorityQueue<T> for MinMaxHeap<T, C> {
+    fn del_min(&mut self) -> T {
+        if self.empty() {
+            panic!("this heap is empty");
+        }
+
+        self.take(0)
+    }
+
+    fn min(&self) -> &T {
+        &self.vec[0]
+    }
+}
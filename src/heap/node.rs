@@ -0,0 +1,275 @@
+use super::super::utility::{Allocator, Global};
+use super::{Compare, MaxComparator, PriorityQueue};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+
+pub type Ptr<N> = Option<NonNull<N>>;
+
+pub trait Meld<T> {
+    type Node;
+
+    fn alloc<A: Allocator>(value: T, alloc: &A) -> NonNull<Self::Node>;
+    fn free<A: Allocator>(node: NonNull<Self::Node>, alloc: &A) -> T;
+    fn data(node: &Self::Node) -> &T;
+    fn merge<C: Compare<T>>(cmp: &C, a: Ptr<Self::Node>, b: Ptr<Self::Node>) -> Ptr<Self::Node>;
+    fn remove<C: Compare<T>>(cmp: &C, root: NonNull<Self::Node>, top: NonNull<Self::Node>) -> Ptr<Self::Node>;
+    fn split(node: NonNull<Self::Node>) -> (Ptr<Self::Node>, Ptr<Self::Node>);
+
+    fn top<C: Compare<T>>(_: &C, root: NonNull<Self::Node>) -> NonNull<Self::Node> {
+        root
+    }
+}
+
+pub fn alloc<N, A: Allocator>(node: N, alloc: &A) -> NonNull<N> {
+    NonNull::new(alloc.malloc_val(node).expect("allocation failed")).unwrap()
+}
+
+pub fn free<N, A: Allocator>(node: NonNull<N>, alloc: &A) -> N {
+    unsafe {
+        let data = ptr::read(node.as_ptr());
+
+        alloc.free(node.as_ptr(), 1);
+
+        data
+    }
+}
+
+pub struct NodeHeap<T, M: Meld<T>, C: Compare<T> = MaxComparator, A: Allocator = Global> {
+    pub(super) root: Ptr<M::Node>,
+    pub(super) size: usize,
+    pub(super) cmp: C,
+    pub(super) alloc: A,
+    marker: PhantomData<(T, M)>,
+}
+
+impl<T: Ord, M: Meld<T>> NodeHeap<T, M> {
+    pub fn new() -> Self {
+        NodeHeap::with_comparator(MaxComparator)
+    }
+}
+
+impl<T, M: Meld<T>, C: Compare<T>> NodeHeap<T, M, C> {
+    pub fn with_comparator(cmp: C) -> Self {
+        NodeHeap::with_comparator_in(cmp, Global)
+    }
+}
+
+impl<T, M: Meld<T>, C: Compare<T>, A: Allocator> NodeHeap<T, M, C, A> {
+    pub fn with_comparator_in(cmp: C, alloc: A) -> Self {
+        NodeHeap {
+            root: None,
+            size: 0,
+            cmp: cmp,
+            alloc: alloc,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Moves every value of `other` into this heap without copying them.
+    ///
+    /// The nodes of `other` are later freed through this heap's allocator, so
+    /// both allocators must compare equal.
+    pub fn meld(&mut self, mut other: Self)
+    where
+        A: PartialEq,
+    {
+        assert!(self.alloc == other.alloc, "allocators differ");
+
+        let root = other.root.take();
+
+        self.root = M::merge(&self.cmp, self.root, root);
+        self.size += other.size;
+        other.size = 0;
+    }
+}
+
+impl<T, M: Meld<T>, C: Compare<T> + Default> Default for NodeHeap<T, M, C> {
+    fn default() -> Self {
+        NodeHeap::with_comparator(Default::default())
+    }
+}
+
+impl<T, M: Meld<T>, C: Compare<T>, A: Allocator> PriorityQueue<T> for NodeHeap<T, M, C, A> {
+    fn insert(&mut self, value: T) {
+        let node = M::alloc(value, &self.alloc);
+
+        self.root = M::merge(&self.cmp, self.root, Some(node));
+        self.size += 1;
+    }
+
+    fn del_max(&mut self) -> T {
+        let root = self.root.expect("this heap is empty");
+        let top = M::top(&self.cmp, root);
+
+        self.root = M::remove(&self.cmp, root, top);
+        self.size -= 1;
+
+        M::free(top, &self.alloc)
+    }
+
+    fn max(&self) -> &T {
+        let root = self.root.expect("this heap is empty");
+
+        unsafe { M::data(&*M::top(&self.cmp, root).as_ptr()) }
+    }
+}
+
+impl<T, M: Meld<T>, C: Compare<T>, A: Allocator> Drop for NodeHeap<T, M, C, A> {
+    fn drop(&mut self) {
+        let mut stack = Vec::new();
+
+        stack.extend(self.root.take());
+        while let Some(node) = stack.pop() {
+            let (a, b) = M::split(node);
+
+            stack.extend(a);
+            stack.extend(b);
+            M::free(node, &self.alloc);
+        }
+    }
+}
+
+pub type Link<T> = Ptr<Node<T>>;
+
+pub struct Item<T> {
+    pub data: T,
+    pub node: NonNull<Node<T>>,
+}
+
+pub struct Node<T> {
+    pub item: NonNull<Item<T>>,
+    pub parent: Link<T>,
+    pub child: Link<T>,
+    pub left: Link<T>,
+    pub right: Link<T>,
+    pub degree: usize,
+    pub mark: bool,
+}
+
+impl<T> Node<T> {
+    pub fn alloc<A: Allocator>(value: T, alloc: &A) -> NonNull<Node<T>> {
+        let item: *mut Item<T> = alloc.malloc(1).expect("allocation failed");
+        let node = match alloc.malloc_val(Node {
+            item: NonNull::new(item).unwrap(),
+            parent: None,
+            child: None,
+            left: None,
+            right: None,
+            degree: 0,
+            mark: false,
+        }) {
+            Ok(node) => NonNull::new(node).unwrap(),
+            Err(_) => {
+                alloc.free(item, 1);
+                panic!("allocation failed");
+            }
+        };
+
+        unsafe {
+            ptr::write(
+                item,
+                Item {
+                    data: value,
+                    node: node,
+                },
+            );
+        }
+
+        node
+    }
+
+    pub fn free<A: Allocator>(node: NonNull<Node<T>>, alloc: &A) -> T {
+        unsafe {
+            let item = node.as_ref().item.as_ptr();
+            let data = ptr::read(&(*item).data);
+
+            alloc.free(item, 1);
+            alloc.free(node.as_ptr(), 1);
+
+            data
+        }
+    }
+
+    pub fn data(&self) -> &T {
+        unsafe { &(*self.item.as_ptr()).data }
+    }
+
+    pub fn swap_items(a: NonNull<Node<T>>, b: NonNull<Node<T>>) {
+        unsafe {
+            let (a, b) = (a.as_ptr(), b.as_ptr());
+
+            ptr::swap(&mut (*a).item, &mut (*b).item);
+            (*(*a).item.as_ptr()).node = NonNull::new(a).unwrap();
+            (*(*b).item.as_ptr()).node = NonNull::new(b).unwrap();
+        }
+    }
+}
+
+pub struct Handle<T>(NonNull<Item<T>>);
+
+pub fn handle<T>(node: NonNull<Node<T>>) -> Handle<T> {
+    unsafe { Handle(node.as_ref().item) }
+}
+
+pub fn node<T>(handle: Handle<T>) -> NonNull<Node<T>> {
+    unsafe { (*handle.0.as_ptr()).node }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Handle({:?})", self.0)
+    }
+}
+
+impl<T, M, C, A> NodeHeap<T, M, C, A>
+where
+    M: Meld<T, Node = Node<T>>,
+    C: Compare<T>,
+    A: Allocator,
+{
+    /// # Safety
+    ///
+    /// `handle` must come from `push` on this heap, or on a heap melded into
+    /// it, and its value must not have been removed yet.
+    pub unsafe fn get(&self, handle: Handle<T>) -> &T {
+        (*node(handle).as_ptr()).data()
+    }
+
+    pub fn push(&mut self, value: T) -> Handle<T> {
+        let node = M::alloc(value, &self.alloc);
+
+        self.root = M::merge(&self.cmp, self.root, Some(node));
+        self.size += 1;
+
+        handle(node)
+    }
+}
@@ -0,0 +1,100 @@
+use super::super::stack::Stack;
+use super::super::utility::{Allocator, Global};
+use super::super::vector::Vector;
+use super::node::{self, Meld, NodeHeap, Ptr};
+use super::{Compare, MaxComparator};
+use std::mem;
+use std::ptr::NonNull;
+
+type Link<T> = Ptr<Node<T>>;
+
+pub struct Node<T> {
+    data: T,
+    child: Link<T>,
+    sibling: Link<T>,
+}
+
+pub struct Pairing;
+
+pub type PairingHeap<T, C = MaxComparator, A = Global> = NodeHeap<T, Pairing, C, A>;
+
+fn merge_pairs<T, C: Compare<T>>(cmp: &C, mut first: Link<T>) -> Link<T> {
+    let mut pairs = Vector::new();
+
+    unsafe {
+        while let Some(mut a) = first {
+            let b = a.as_ref().sibling;
+
+            a.as_mut().sibling = None;
+            first = match b {
+                Some(mut b) => {
+                    let next = b.as_ref().sibling;
+                    b.as_mut().sibling = None;
+                    pairs.push(Pairing::merge(cmp, Some(a), Some(b)));
+                    next
+                }
+                None => {
+                    pairs.push(Some(a));
+                    None
+                }
+            };
+        }
+    }
+
+    let mut root = None;
+    while let Some(pair) = pairs.try_pop() {
+        root = Pairing::merge(cmp, pair, root);
+    }
+
+    root
+}
+
+impl<T> Meld<T> for Pairing {
+    type Node = Node<T>;
+
+    fn alloc<A: Allocator>(value: T, alloc: &A) -> NonNull<Node<T>> {
+        node::alloc(
+            Node {
+                data: value,
+                child: None,
+                sibling: None,
+            },
+            alloc,
+        )
+    }
+
+    fn free<A: Allocator>(node: NonNull<Node<T>>, alloc: &A) -> T {
+        node::free(node, alloc).data
+    }
+
+    fn data(node: &Node<T>) -> &T {
+        &node.data
+    }
+
+    fn merge<C: Compare<T>>(cmp: &C, a: Link<T>, b: Link<T>) -> Link<T> {
+        let (mut a, mut b) = match (a, b) {
+            (None, b) => return b,
+            (a, None) => return a,
+            (Some(a), Some(b)) => (a, b),
+        };
+
+        unsafe {
+            if cmp.less(&a.as_ref().data, &b.as_ref().data) {
+                mem::swap(&mut a, &mut b);
+            }
+
+            b.as_mut().sibling = a.as_ref().child;
+            a.as_mut().child = Some(b);
+        }
+
+        Some(a)
+    }
+
+    fn remove<C: Compare<T>>(cmp: &C, root: NonNull<Node<T>>, _: NonNull<Node<T>>) -> Link<T> {
+        unsafe { merge_pairs(cmp, root.as_ref().child) }
+    }
+
+    fn split(node: NonNull<Node<T>>) -> (Link<T>, Link<T>) {
+        unsafe { (node.as_ref().child, node.as_ref().sibling) }
+    }
+}
@@ -0,0 +1,82 @@
+use super::super::utility::{Allocator, Global};
+use super::node::{self, Meld, NodeHeap, Ptr};
+use super::{Compare, MaxComparator};
+use std::mem;
+use std::ptr::NonNull;
+
+type Link<T> = Ptr<Node<T>>;
+
+pub struct Node<T> {
+    data: T,
+    lc: Link<T>,
+    rc: Link<T>,
+}
+
+pub struct Skew;
+
+pub type SkewHeap<T, C = MaxComparator, A = Global> = NodeHeap<T, Skew, C, A>;
+
+impl<T> Meld<T> for Skew {
+    type Node = Node<T>;
+
+    fn alloc<A: Allocator>(value: T, alloc: &A) -> NonNull<Node<T>> {
+        node::alloc(
+            Node {
+                data: value,
+                lc: None,
+                rc: None,
+            },
+            alloc,
+        )
+    }
+
+    fn free<A: Allocator>(node: NonNull<Node<T>>, alloc: &A) -> T {
+        node::free(node, alloc).data
+    }
+
+    fn data(node: &Node<T>) -> &T {
+        &node.data
+    }
+
+    fn merge<C: Compare<T>>(cmp: &C, mut a: Link<T>, mut b: Link<T>) -> Link<T> {
+        let mut root = None;
+        let mut slot = &mut root as *mut Link<T>;
+
+        unsafe {
+            loop {
+                let (mut x, mut y) = match (a, b) {
+                    (None, b) => {
+                        *slot = b;
+                        break;
+                    }
+                    (a, None) => {
+                        *slot = a;
+                        break;
+                    }
+                    (Some(a), Some(b)) => (a, b),
+                };
+
+                if cmp.less(&x.as_ref().data, &y.as_ref().data) {
+                    mem::swap(&mut x, &mut y);
+                }
+
+                let node = x.as_mut();
+                *slot = Some(x);
+                a = node.rc;
+                b = Some(y);
+                node.rc = node.lc;
+                slot = &mut node.lc;
+            }
+        }
+
+        root
+    }
+
+    fn remove<C: Compare<T>>(cmp: &C, root: NonNull<Node<T>>, _: NonNull<Node<T>>) -> Link<T> {
+        unsafe { Skew::merge(cmp, root.as_ref().lc, root.as_ref().rc) }
+    }
+
+    fn split(node: NonNull<Node<T>>) -> (Link<T>, Link<T>) {
+        unsafe { (node.as_ref().lc, node.as_ref().rc) }
+    }
+}
@@ -1,9 +1,17 @@
-mod utility;
+#![allow(
+    clippy::len_without_is_empty,
+    clippy::needless_return,
+    clippy::new_without_default,
+    clippy::redundant_field_names
+)]
+
 pub mod bin_tree;
 pub mod heap;
 pub mod list;
 pub mod queue;
+pub mod ring_buffer;
 pub mod search;
 pub mod sort;
 pub mod stack;
+pub mod utility;
 pub mod vector;
@@ -1,24 +1,31 @@
-use super::queue::Queue;
+use super::queue::{Deque, Queue};
 use super::search::Ordered;
 use super::sort::Sort;
-use super::utility::{free, malloc_val};
+use super::utility::{AllocErr, Allocator, Global, IndexErr};
 use std::cmp::{Ordering, PartialEq};
-use std::default::Default;
 use std::iter::DoubleEndedIterator;
 use std::marker::PhantomData;
+use std::mem::MaybeUninit;
 use std::ops::{Drop, FnMut, Index, IndexMut};
 use std::ptr::{self, NonNull};
 
 type Link<T> = Option<NonNull<Node<T>>>;
 
-#[derive(Clone, Copy)]
-pub struct Iter<'a, T: 'a> {
+pub struct RawIter<'a, T: 'a> {
     ptr: *mut Node<T>,
     end: *mut Node<T>,
     marker: PhantomData<&'a mut T>,
 }
 
-impl<'a, T: 'a> Iterator for Iter<'a, T> {
+impl<'a, T: 'a> Clone for RawIter<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: 'a> Copy for RawIter<'a, T> {}
+
+impl<'a, T: 'a> Iterator for RawIter<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -29,12 +36,12 @@ impl<'a, T: 'a> Iterator for Iter<'a, T> {
             let ptr = self.ptr;
             self.ptr = (*ptr).succ().unwrap().as_ptr();
 
-            return Some(&mut (*ptr).data);
+            return Some((*ptr).data_mut());
         }
     }
 }
 
-impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
+impl<'a, T: 'a> DoubleEndedIterator for RawIter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         unsafe {
             if self.end == self.ptr {
@@ -42,38 +49,75 @@ impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
             }
             self.end = (*self.end).pred().unwrap().as_ptr();
 
-            return Some(&mut (*self.end).data);
+            return Some((*self.end).data_mut());
         }
     }
 }
 
+pub struct Iter<'a, T: 'a>(RawIter<'a, T>);
+
+impl<'a, T: 'a> Iterator for Iter<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
 pub struct Node<T> {
-    pub data: T,
+    data: MaybeUninit<T>,
     pred: Link<T>,
     succ: Link<T>,
 }
 
 impl<T> Node<T> {
-    fn new(value: &T, posi0: Link<T>, posi1: Link<T>) -> Self {
+    fn new(value: T, posi0: Link<T>, posi1: Link<T>) -> Self {
         Node {
-            data: unsafe { ptr::read(value) },
+            data: MaybeUninit::new(value),
             pred: posi0,
             succ: posi1,
         }
     }
 
-    fn insert_as_pred(&mut self, value: &T) {
+    fn sentinel() -> Self {
+        Node {
+            data: MaybeUninit::uninit(),
+            pred: None,
+            succ: None,
+        }
+    }
+
+    unsafe fn data(&self) -> &T {
+        &*self.data.as_ptr()
+    }
+
+    unsafe fn data_mut(&mut self) -> &mut T {
+        &mut *self.data.as_mut_ptr()
+    }
+
+    fn insert_as_pred<A: Allocator>(&mut self, value: T, alloc: &A) -> Result<(), AllocErr> {
         match self.pred {
             Some(mut node) => unsafe {
-                node.as_mut().succ = NonNull::new(malloc_val(
-                    &(Node::new(value, Some(node), NonNull::new(self))),
-                ));
+                node.as_mut().succ = NonNull::new(alloc.malloc_val(Node::new(
+                    value,
+                    Some(node),
+                    NonNull::new(self),
+                ))?);
                 self.pred = node.as_mut().succ;
             },
             _ => {
-                self.pred = NonNull::new(malloc_val(&(Node::new(value, None, NonNull::new(self)))));
+                self.pred =
+                    NonNull::new(alloc.malloc_val(Node::new(value, None, NonNull::new(self)))?);
             }
         }
+
+        Ok(())
     }
 
     fn link(a: &mut Node<T>, b: &mut Node<T>) {
@@ -90,18 +134,30 @@ impl<T> Node<T> {
     }
 }
 
-pub struct List<T> {
+pub struct List<T, A: Allocator = Global> {
     head: *mut Node<T>,
     trail: *mut Node<T>,
     len: usize,
+    alloc: A,
 }
 
-impl<T: Default> List<T> {
+impl<T> List<T> {
     pub fn new() -> Self {
-        let list = List::<T> {
-            head: malloc_val(&Node::new(&Default::default(), None, None)),
-            trail: malloc_val(&Node::new(&Default::default(), None, None)),
+        List::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator> List<T, A> {
+    pub fn new_in(alloc: A) -> Self {
+        let list = List::<T, A> {
+            head: alloc
+                .malloc_val(Node::sentinel())
+                .expect("allocation failed"),
+            trail: alloc
+                .malloc_val(Node::sentinel())
+                .expect("allocation failed"),
             len: 0,
+            alloc: alloc,
         };
         unsafe {
             (*list.head).succ = NonNull::new(list.trail);
@@ -112,7 +168,11 @@ impl<T: Default> List<T> {
     }
 }
 
-impl<T> List<T> {
+impl<T, A: Allocator> List<T, A> {
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     pub fn len(&self) -> usize {
         self.len
     }
@@ -124,7 +184,7 @@ impl<T> List<T> {
         false
     }
 
-    fn get(&self, index: usize) -> Link<T> {
+    fn node_at(&self, index: usize) -> Link<T> {
         if index > self.len {
             return None;
         }
@@ -154,7 +214,7 @@ impl<T> List<T> {
     where
         F: FnMut(&mut T),
     {
-        let mut it = self.get(lo);
+        let mut it = self.node_at(lo);
         let mut cnt = lo;
 
         unsafe {
@@ -163,7 +223,7 @@ impl<T> List<T> {
                     break;
                 }
 
-                func(&mut node.as_mut().data);
+                func(node.as_mut().data_mut());
 
                 it = node.as_ref().succ;
                 cnt += 1;
@@ -171,29 +231,76 @@ impl<T> List<T> {
         }
     }
 
-    pub fn insert(&mut self, index: usize, value: &T) {
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        self.node_at(index)
+            .map(|node| unsafe { (*node.as_ptr()).data() })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        self.node_at(index)
+            .map(|node| unsafe { (*node.as_ptr()).data_mut() })
+    }
+
+    pub fn insert(&mut self, index: usize, value: T) {
+        match self.try_insert(index, value) {
+            Err(IndexErr::Alloc(_)) => panic!("allocation failed"),
+            Err(_) => panic!("bound error!"),
+            Ok(()) => (),
+        }
+    }
+
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), IndexErr> {
         unsafe {
-            if let Some(mut node) = self.get(index) {
-                node.as_mut().insert_as_pred(value);
+            if let Some(mut node) = self.node_at(index) {
+                node.as_mut().insert_as_pred(value, &self.alloc)?;
             } else {
-                panic!("bound error!");
+                return Err(IndexErr::OutOfBounds {
+                    index: index,
+                    len: self.len,
+                });
             }
         }
         self.len += 1;
+
+        Ok(())
     }
 
     pub fn remove(&mut self, lo: usize, hi: usize) {
-        if lo >= hi || hi > self.len {
+        if self.try_remove(lo, hi).is_err() {
             panic!("bound error!");
         }
+    }
+
+    pub fn try_remove(&mut self, lo: usize, hi: usize) -> Result<(), IndexErr> {
+        if lo > hi {
+            return Err(IndexErr::InvalidRange { lo: lo, hi: hi });
+        }
+        if hi > self.len {
+            return Err(IndexErr::OutOfBounds {
+                index: hi,
+                len: self.len,
+            });
+        }
+        if lo == hi {
+            return Ok(());
+        }
 
-        if let Some(mut it) = self.get(lo) {
+        if let Some(mut it) = self.node_at(lo) {
             unsafe {
                 let begin = it.as_ref().pred;
-                let end = self.get(hi);
+                let end = self.node_at(hi);
                 for _ in 0..(hi - lo) {
                     let next = it.as_ref().succ;
-                    free(it.as_mut(), 1).unwrap();
+                    ptr::drop_in_place(it.as_mut().data.as_mut_ptr());
+                    self.alloc.free(it.as_mut(), 1);
                     if let Some(node) = next {
                         it = node;
                     }
@@ -204,22 +311,307 @@ impl<T> List<T> {
         }
 
         self.len -= hi - lo;
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        let len = self.len;
+
+        self.remove(0, len);
+    }
+
+    pub fn cursor_front_mut<'a>(&'a mut self) -> CursorMut<'a, T, A> {
+        let current = unsafe { (*self.head).succ.unwrap() };
+
+        CursorMut {
+            current: current,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back_mut<'a>(&'a mut self) -> CursorMut<'a, T, A> {
+        let mut cursor = CursorMut {
+            current: NonNull::new(self.trail).unwrap(),
+            index: self.len,
+            list: self,
+        };
+        cursor.move_prev();
+
+        cursor
+    }
+
+    fn unlink(&mut self, mut node: NonNull<Node<T>>) -> T {
+        unsafe {
+            let pred = node.as_ref().pred;
+            let succ = node.as_ref().succ;
+            let data = ptr::read(node.as_ref().data.as_ptr());
+
+            pred.unwrap().as_mut().succ = succ;
+            succ.unwrap().as_mut().pred = pred;
+            self.alloc.free(node.as_mut(), 1);
+            self.len -= 1;
+
+            data
+        }
     }
 }
 
-impl<'a, T: 'a> List<T> {
-    pub fn iter(&mut self) -> Iter<'a, T> {
-        Iter {
-            ptr: unsafe { (*self.head).succ().unwrap().as_ptr() },
+impl<T, A: Allocator> List<T, A> {
+    pub fn iter<'a>(&'a mut self) -> Iter<'a, T> {
+        Iter(unsafe { self.raw_iter() })
+    }
+
+    unsafe fn raw_iter<'a>(&mut self) -> RawIter<'a, T> {
+        RawIter {
+            ptr: (*self.head).succ().unwrap().as_ptr(),
             end: self.trail,
             marker: PhantomData,
         }
     }
 }
 
-impl<T: PartialEq> List<T> {
+pub struct CursorMut<'a, T: 'a, A: 'a + Allocator = Global> {
+    current: NonNull<Node<T>>,
+    index: usize,
+    list: &'a mut List<T, A>,
+}
+
+impl<'a, T: 'a, A: 'a + Allocator> CursorMut<'a, T, A> {
+    fn ghost(&self) -> bool {
+        self.current.as_ptr() == self.list.trail
+    }
+
+    pub fn index(&self) -> Option<usize> {
+        if self.ghost() {
+            return None;
+        }
+
+        Some(self.index)
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        if self.ghost() {
+            return None;
+        }
+
+        unsafe { Some(self.current.as_mut().data_mut()) }
+    }
+
+    pub fn move_next(&mut self) {
+        unsafe {
+            if self.ghost() {
+                self.current = (*self.list.head).succ.unwrap();
+                self.index = 0;
+            } else {
+                self.current = self.current.as_ref().succ.unwrap();
+                self.index += 1;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        unsafe {
+            let pred = self.current.as_ref().pred.unwrap();
+
+            if pred.as_ptr() == self.list.head {
+                self.current = NonNull::new(self.list.trail).unwrap();
+                self.index = self.list.len;
+            } else {
+                if self.ghost() {
+                    self.index = self.list.len;
+                }
+                self.current = pred;
+                self.index -= 1;
+            }
+        }
+    }
+
+    pub fn insert_before(&mut self, value: T) {
+        unsafe {
+            self.current
+                .as_mut()
+                .insert_as_pred(value, &self.list.alloc)
+                .expect("allocation failed");
+        }
+        self.list.len += 1;
+        self.index += 1;
+    }
+
+    pub fn insert_after(&mut self, value: T) {
+        unsafe {
+            let mut succ = if self.ghost() {
+                self.index += 1;
+                (*self.list.head).succ.unwrap()
+            } else {
+                self.current.as_ref().succ.unwrap()
+            };
+
+            succ.as_mut()
+                .insert_as_pred(value, &self.list.alloc)
+                .expect("allocation failed");
+        }
+        self.list.len += 1;
+    }
+
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.ghost() {
+            return None;
+        }
+
+        let node = self.current;
+        self.current = unsafe { node.as_ref().succ.unwrap() };
+
+        Some(self.list.unlink(node))
+    }
+
+    /// Links the nodes of `other` in before the current position.
+    ///
+    /// They are later freed through this list's allocator, so both
+    /// allocators must compare equal.
+    pub fn splice_before(&mut self, mut other: List<T, A>)
+    where
+        A: PartialEq,
+    {
+        assert!(self.list.alloc == other.alloc, "allocators differ");
+        if other.empty() {
+            return;
+        }
+
+        unsafe {
+            let mut first = (*other.head).succ.unwrap();
+            let mut last = (*other.trail).pred.unwrap();
+            let mut pred = self.current.as_ref().pred.unwrap();
+
+            Node::link(pred.as_mut(), first.as_mut());
+            Node::link(last.as_mut(), self.current.as_mut());
+            Node::link(&mut *other.head, &mut *other.trail);
+        }
+        self.list.len += other.len;
+        self.index += other.len;
+        other.len = 0;
+    }
+}
+
+impl<'a, T: 'a, A: 'a + Allocator + Clone> CursorMut<'a, T, A> {
+    pub fn split_after(&mut self) -> List<T, A> {
+        let mut list = List::new_in(self.list.alloc.clone());
+        let (mut pred, len) = if self.ghost() {
+            (NonNull::new(self.list.head).unwrap(), self.list.len)
+        } else {
+            (self.current, self.list.len - self.index - 1)
+        };
+
+        if len == 0 {
+            return list;
+        }
+
+        unsafe {
+            let mut first = pred.as_ref().succ.unwrap();
+            let mut last = (*self.list.trail).pred.unwrap();
+
+            Node::link(&mut *list.head, first.as_mut());
+            Node::link(last.as_mut(), &mut *list.trail);
+            Node::link(pred.as_mut(), &mut *self.list.trail);
+        }
+        list.len = len;
+        self.list.len -= len;
+        if self.ghost() {
+            self.index = 0;
+        }
+
+        list
+    }
+}
+
+impl<T, A: Allocator> List<T, A> {
+    pub fn merge_sort<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let less = |a: NonNull<Node<T>>, b: NonNull<Node<T>>| unsafe {
+            compare(a.as_ref().data(), b.as_ref().data()) == Ordering::Less
+        };
+        let trail = NonNull::new(self.trail).unwrap();
+        let mut runs = Vec::new();
+
+        unsafe {
+            let mut it = (*self.head).succ.unwrap();
+            while it != trail {
+                let mut start = it;
+                let mut next = it.as_ref().succ.unwrap();
+
+                if next != trail && less(next, it) {
+                    while next != trail && less(next, start) {
+                        let succ = next.as_ref().succ.unwrap();
+                        List::<T, A>::move_before(next, start);
+                        start = next;
+                        next = succ;
+                    }
+                } else {
+                    while next != trail && !less(next, it) {
+                        it = next;
+                        next = it.as_ref().succ.unwrap();
+                    }
+                }
+
+                runs.push(start);
+                it = next;
+            }
+
+            while runs.len() > 1 {
+                let mut merged = Vec::with_capacity((runs.len() + 1) / 2);
+
+                for i in (0..runs.len()).step_by(2) {
+                    if i + 1 == runs.len() {
+                        merged.push(runs[i]);
+                        break;
+                    }
+
+                    let end = if i + 2 < runs.len() {
+                        runs[i + 2]
+                    } else {
+                        trail
+                    };
+                    let mut start = runs[i];
+                    let mut first = runs[i];
+                    let mut second = runs[i + 1];
+
+                    while first != second && second != end {
+                        if less(second, first) {
+                            let succ = second.as_ref().succ.unwrap();
+                            List::<T, A>::move_before(second, first);
+                            if first == start {
+                                start = second;
+                            }
+                            second = succ;
+                        } else {
+                            first = first.as_ref().succ.unwrap();
+                        }
+                    }
+
+                    merged.push(start);
+                }
+
+                runs = merged;
+            }
+        }
+    }
+
+    unsafe fn move_before(mut node: NonNull<Node<T>>, mut posi: NonNull<Node<T>>) {
+        let mut pred = node.as_ref().pred.unwrap();
+        let mut succ = node.as_ref().succ.unwrap();
+
+        Node::link(pred.as_mut(), succ.as_mut());
+        Node::link(posi.as_ref().pred.unwrap().as_mut(), node.as_mut());
+        Node::link(node.as_mut(), posi.as_mut());
+    }
+}
+
+impl<T: PartialEq, A: Allocator> List<T, A> {
     pub fn find(&mut self, value: &T, lo: usize, hi: usize) -> Option<usize> {
-        let mut it = self.get(lo);
+        let mut it = self.node_at(lo);
         let mut cnt = lo;
 
         unsafe {
@@ -228,7 +620,7 @@ impl<T: PartialEq> List<T> {
                     break;
                 }
 
-                if *value == node.as_ref().data {
+                if *value == *node.as_ref().data() {
                     return Some(cnt);
                 }
 
@@ -249,46 +641,43 @@ impl<T: PartialEq> List<T> {
             let mut it = (*self.head).succ;
             let end = NonNull::new(self.trail);
 
-            while let Some(node) = it {
+            while it != end {
+                let node = it.unwrap();
                 let mut next = node.as_ref().succ;
-                while let Some(mut other) = next {
+                while next != end {
+                    let mut other = next.unwrap();
                     next = other.as_ref().succ;
-                    if node.as_ref().data == other.as_ref().data {
+                    if node.as_ref().data() == other.as_ref().data() {
                         let pred = other.as_ref().pred;
 
                         pred.unwrap().as_mut().succ = next;
                         next.unwrap().as_mut().pred = pred;
-                        free(other.as_mut(), 1).unwrap();
+                        ptr::drop_in_place(other.as_mut().data.as_mut_ptr());
+                        self.alloc.free(other.as_mut(), 1);
                         self.len -= 1;
                     }
-                    if next == end {
-                        break;
-                    }
                 }
                 it = node.as_ref().succ;
-                if it == end {
-                    break;
-                }
             }
         }
     }
 }
 
-impl<T: Ord> List<T> {
+impl<T: Ord, A: Allocator> List<T, A> {
     pub fn sort<F>(&mut self, cmp: F)
     where
         F: Fn(&T, &T) -> Ordering,
     {
         unsafe {
-            if let Some(mut it) = self.get(1) {
+            if let Some(mut it) = self.node_at(1) {
                 while it.as_ptr() != self.trail {
-                    let mut it0 = self.get(0).unwrap();
+                    let mut it0 = self.node_at(0).unwrap();
                     let mut pred = it.as_ref().pred.unwrap();
                     let mut succ = it.as_ref().succ.unwrap();
 
                     while it0 != it {
                         let next = it0.as_ref().succ.unwrap();
-                        if cmp(&it0.as_ref().data, &it.as_ref().data) == Ordering::Greater {
+                        if cmp(it0.as_ref().data(), it.as_ref().data()) == Ordering::Greater {
                             Node::link(it0.as_ref().pred.unwrap().as_mut(), it.as_mut());
                             Node::link(it.as_mut(), it0.as_mut());
                             Node::link(pred.as_mut(), succ.as_mut());
@@ -305,20 +694,54 @@ impl<T: Ord> List<T> {
     }
 }
 
-impl<T> Drop for List<T> {
-    fn drop(&mut self) {
-        let len = self.len;
+impl<T: Clone, A: Allocator + Clone> Clone for List<T, A> {
+    fn clone(&self) -> Self {
+        let mut list = List::new_in(self.alloc.clone());
+
+        list.clone_from(self);
+
+        list
+    }
 
-        if len != 0 {
-            self.remove(0, len);
+    fn clone_from(&mut self, source: &Self) {
+        if self.len > source.len {
+            let len = self.len;
+            self.remove(source.len, len);
         }
 
-        free(self.head, 1).unwrap();
-        free(self.trail, 1).unwrap();
+        unsafe {
+            let mut it = (*self.head).succ;
+            let mut other = (*source.head).succ;
+
+            for _ in 0..self.len {
+                let mut node = it.unwrap();
+                let src = other.unwrap();
+
+                node.as_mut().data_mut().clone_from(src.as_ref().data());
+                it = node.as_ref().succ;
+                other = src.as_ref().succ;
+            }
+
+            for _ in self.len..source.len {
+                let src = other.unwrap();
+                let len = self.len;
+
+                self.insert(len, src.as_ref().data().clone());
+                other = src.as_ref().succ;
+            }
+        }
+    }
+}
+
+impl<T, A: Allocator> Drop for List<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+        self.alloc.free(self.head, 1);
+        self.alloc.free(self.trail, 1);
     }
 }
 
-impl<T> Index<usize> for List<T> {
+impl<T, A: Allocator> Index<usize> for List<T, A> {
     type Output = T;
 
     fn index(&self, i: usize) -> &T {
@@ -326,63 +749,103 @@ impl<T> Index<usize> for List<T> {
             panic!("bound error!");
         }
 
-        let node = self.get(i).unwrap();
+        let node = self.node_at(i).unwrap();
 
-        unsafe { &(*node.as_ptr()).data }
+        unsafe { (*node.as_ptr()).data() }
     }
 }
 
-impl<T> IndexMut<usize> for List<T> {
+impl<T, A: Allocator> IndexMut<usize> for List<T, A> {
     fn index_mut(&mut self, i: usize) -> &mut T {
         if i >= self.len() {
             panic!("bound error!");
         }
 
-        let node = self.get(i).unwrap();
+        let node = self.node_at(i).unwrap();
 
-        unsafe { &mut (*node.as_ptr()).data }
+        unsafe { (*node.as_ptr()).data_mut() }
     }
 }
 
-impl<T> Queue<T> for List<T> {
-    fn enqueue(&mut self, value: &T) {
-        let size = self.len();
-        self.insert(size, value);
+impl<T, A: Allocator> Queue<T> for List<T, A> {
+    fn enqueue(&mut self, value: T) {
+        self.push_back(value);
     }
 
-    fn dequeue(&mut self) -> T {
-        let size = self.len();
-        if size == 0 {
-            panic!("this queue is empty");
+    fn try_dequeue(&mut self) -> Option<T> {
+        self.try_pop_front()
+    }
+
+    fn try_front(&mut self) -> Option<&mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        unsafe { Some((*self.head).succ.unwrap().as_mut().data_mut()) }
+    }
+}
+
+impl<T, A: Allocator> Deque<T> for List<T, A> {
+    fn push_front(&mut self, value: T) {
+        unsafe {
+            (*self.head)
+                .succ
+                .unwrap()
+                .as_mut()
+                .insert_as_pred(value, &self.alloc)
+                .expect("allocation failed");
         }
+        self.len += 1;
+    }
+
+    fn push_back(&mut self, value: T) {
         unsafe {
-            let out = ptr::read(&(self[0]));
-            self.remove(size - 1, size);
+            (*self.trail)
+                .insert_as_pred(value, &self.alloc)
+                .expect("allocation failed");
+        }
+        self.len += 1;
+    }
+
+    fn try_pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = unsafe { (*self.head).succ.unwrap() };
+        Some(self.unlink(node))
+    }
 
-            out
+    fn try_pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
         }
+
+        let node = unsafe { (*self.trail).pred.unwrap() };
+        Some(self.unlink(node))
     }
 
-    fn front(&mut self) -> &mut T {
-        if self.len() == 0 {
-            panic!("this queue is empty");
+    fn try_back(&mut self) -> Option<&mut T> {
+        if self.len == 0 {
+            return None;
         }
-        &mut self[0]
+
+        unsafe { Some((*self.trail).pred.unwrap().as_mut().data_mut()) }
     }
 }
 
-impl<'a, T: 'a + Copy> Sort<Iter<'a, T>> for List<T> {
+impl<'a, T: 'a, A: Allocator> Sort<RawIter<'a, T>> for List<T, A> {
     fn len(&self) -> usize {
         self.len()
     }
 
-    fn iter(&mut self) -> Iter<'a, T> {
-        self.iter()
+    unsafe fn iter(&mut self) -> RawIter<'a, T> {
+        self.raw_iter()
     }
 }
 
-impl<T: Ord> Ordered<T> for List<T> {
-    fn push(&mut self, value: &T) {
+impl<T: Ord, A: Allocator> Ordered<T> for List<T, A> {
+    fn push(&mut self, value: T) {
         let len = self.len();
         self.insert(len, value);
         self.sort(|a, b| a.cmp(b));
@@ -396,7 +859,7 @@ macro_rules! list {
             let mut tmp = List::new();
             $(
                 let len = tmp.len();
-                tmp.insert(len, &$e);
+                tmp.insert(len, $e);
             )*
             tmp
         }
@@ -1,11 +1,44 @@
 pub trait Queue<T> {
-    fn enqueue(&mut self, value: &T);
-    fn dequeue(&mut self) -> T;
-    fn front(&mut self) -> &mut T;
+    fn enqueue(&mut self, value: T);
+    fn try_dequeue(&mut self) -> Option<T>;
+    fn try_front(&mut self) -> Option<&mut T>;
+
+    fn dequeue(&mut self) -> T {
+        self.try_dequeue().expect("this queue is empty")
+    }
+
+    fn front(&mut self) -> &mut T {
+        self.try_front().expect("this queue is empty")
+    }
 }
 
-pub trait PriorityQueue<T: PartialOrd> {
-    fn insert(&mut self, value: &T);
+pub trait Deque<T>: Queue<T> {
+    fn push_front(&mut self, value: T);
+    fn push_back(&mut self, value: T);
+    fn try_pop_front(&mut self) -> Option<T>;
+    fn try_pop_back(&mut self) -> Option<T>;
+    fn try_back(&mut self) -> Option<&mut T>;
+
+    fn pop_front(&mut self) -> T {
+        self.try_pop_front().expect("this deque is empty")
+    }
+
+    fn pop_back(&mut self) -> T {
+        self.try_pop_back().expect("this deque is empty")
+    }
+
+    fn back(&mut self) -> &mut T {
+        self.try_back().expect("this deque is empty")
+    }
+}
+
+pub trait PriorityQueue<T> {
+    fn insert(&mut self, value: T);
     fn del_max(&mut self) -> T;
     fn max(&self) -> &T;
 }
+
+pub trait DoubleEndedPriorityQueue<T>: PriorityQueue<T> {
+    fn del_min(&mut self) -> T;
+    fn min(&self) -> &T;
+}
@@ -0,0 +1,460 @@
+use super::queue::{Deque, Queue};
+use super::stack::Stack;
+use super::utility::{AllocErr, Allocator, Global};
+use std::fmt;
+use std::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use std::ptr;
+use std::slice;
+
+const DEFAULT_CAPACITY: usize = 8;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Overflow {
+    Grow,
+    Reject,
+    Overwrite,
+}
+
+pub struct Iter<'a, T: 'a> {
+    ptr: *const T,
+    capacity: usize,
+    head: usize,
+    tail: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: 'a> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            ptr: self.ptr,
+            capacity: self.capacity,
+            head: self.head,
+            tail: self.tail,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.head == self.tail {
+            return None;
+        }
+
+        let i = self.head % self.capacity;
+        self.head += 1;
+
+        unsafe { Some(&(*self.ptr.add(i))) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.tail - self.head;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.tail == self.head {
+            return None;
+        }
+
+        self.tail -= 1;
+
+        unsafe { Some(&(*self.ptr.add(self.tail % self.capacity))) }
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for Iter<'a, T> {}
+
+pub struct RingBuffer<T, A: Allocator = Global> {
+    ptr: *mut T,
+    capacity: usize,
+    head: usize,
+    len: usize,
+    overflow: Overflow,
+    alloc: A,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn new() -> RingBuffer<T> {
+        RingBuffer::new_in(Global)
+    }
+
+    pub fn with_capacity(capacity: usize) -> RingBuffer<T> {
+        RingBuffer::with_capacity_in(capacity, Global)
+    }
+
+    pub fn with_overflow(capacity: usize, overflow: Overflow) -> RingBuffer<T> {
+        RingBuffer::with_overflow_in(capacity, overflow, Global)
+    }
+}
+
+impl<T, A: Allocator> RingBuffer<T, A> {
+    pub fn new_in(alloc: A) -> RingBuffer<T, A> {
+        RingBuffer::with_capacity_in(DEFAULT_CAPACITY, alloc)
+    }
+
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> RingBuffer<T, A> {
+        RingBuffer::with_overflow_in(capacity, Overflow::Grow, alloc)
+    }
+
+    pub fn with_overflow_in(capacity: usize, overflow: Overflow, alloc: A) -> RingBuffer<T, A> {
+        if overflow != Overflow::Grow && capacity == 0 {
+            panic!("bounded ring buffer needs a capacity");
+        }
+
+        RingBuffer {
+            ptr: alloc.malloc(capacity).expect("allocation failed"),
+            capacity: capacity,
+            head: 0,
+            len: 0,
+            overflow: overflow,
+            alloc: alloc,
+        }
+    }
+
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    fn slot(&self, index: usize) -> *mut T {
+        let i = self.head + index;
+
+        if i >= self.capacity {
+            unsafe { self.ptr.add(i - self.capacity) }
+        } else {
+            unsafe { self.ptr.add(i) }
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        unsafe { Some(&(*self.slot(index))) }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        unsafe { Some(&mut (*self.slot(index))) }
+    }
+
+    pub fn try_push_back(&mut self, value: T) -> Result<(), T> {
+        if self.full() {
+            match self.overflow {
+                Overflow::Grow => self.expand().expect("allocation failed"),
+                Overflow::Reject => return Err(value),
+                Overflow::Overwrite => drop(self.try_pop_front()),
+            }
+        }
+
+        unsafe {
+            ptr::write(self.slot(self.len), value);
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub fn try_push_front(&mut self, value: T) -> Result<(), T> {
+        if self.full() {
+            match self.overflow {
+                Overflow::Grow => self.expand().expect("allocation failed"),
+                Overflow::Reject => return Err(value),
+                Overflow::Overwrite => drop(self.try_pop_back()),
+            }
+        }
+
+        self.head = if self.head == 0 {
+            self.capacity - 1
+        } else {
+            self.head - 1
+        };
+        unsafe {
+            ptr::write(self.ptr.add(self.head), value);
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        unsafe {
+            if self.head + self.len <= self.capacity {
+                (
+                    slice::from_raw_parts(self.ptr.add(self.head), self.len),
+                    slice::from_raw_parts(self.ptr, 0),
+                )
+            } else {
+                let first = self.capacity - self.head;
+                (
+                    slice::from_raw_parts(self.ptr.add(self.head), first),
+                    slice::from_raw_parts(self.ptr, self.len - first),
+                )
+            }
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        unsafe {
+            if self.head + self.len <= self.capacity {
+                (
+                    slice::from_raw_parts_mut(self.ptr.add(self.head), self.len),
+                    slice::from_raw_parts_mut(self.ptr, 0),
+                )
+            } else {
+                let first = self.capacity - self.head;
+                (
+                    slice::from_raw_parts_mut(self.ptr.add(self.head), first),
+                    slice::from_raw_parts_mut(self.ptr, self.len - first),
+                )
+            }
+        }
+    }
+
+    pub fn rotate_left(&mut self, n: usize) {
+        if n > self.len {
+            panic!("array bound!");
+        }
+
+        if n > self.len / 2 {
+            let k = self.len - n;
+            return self.rotate_right(k);
+        }
+
+        for _ in 0..n {
+            unsafe {
+                let value = ptr::read(self.slot(0));
+                ptr::write(self.slot(self.len), value);
+            }
+            self.head = (self.head + 1) % self.capacity;
+        }
+    }
+
+    pub fn rotate_right(&mut self, n: usize) {
+        if n > self.len {
+            panic!("array bound!");
+        }
+
+        if n > self.len / 2 {
+            let k = self.len - n;
+            return self.rotate_left(k);
+        }
+
+        for _ in 0..n {
+            self.head = (self.head + self.capacity - 1) % self.capacity;
+            unsafe {
+                let value = ptr::read(self.slot(self.len));
+                ptr::write(self.slot(0), value);
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        let (a, b) = self.as_mut_slices();
+        let (a, b) = (a as *mut [T], b as *mut [T]);
+
+        self.head = 0;
+        self.len = 0;
+        unsafe {
+            ptr::drop_in_place(a);
+            ptr::drop_in_place(b);
+        }
+    }
+
+    pub fn iter<'a>(&'a self) -> Iter<'a, T> {
+        Iter {
+            ptr: self.ptr,
+            capacity: self.capacity,
+            head: self.head,
+            tail: self.head + self.len,
+            marker: PhantomData,
+        }
+    }
+
+    fn expand(&mut self) -> Result<(), AllocErr> {
+        let capacity = if self.capacity == 0 {
+            DEFAULT_CAPACITY
+        } else {
+            self.capacity
+                .checked_mul(2)
+                .ok_or(AllocErr::CapacityOverflow)?
+        };
+
+        unsafe {
+            let new_ptr: *mut T = self.alloc.malloc(capacity)?;
+            let (a, b) = self.as_slices();
+            ptr::copy_nonoverlapping(a.as_ptr(), new_ptr, a.len());
+            ptr::copy_nonoverlapping(b.as_ptr(), new_ptr.add(a.len()), b.len());
+            self.alloc.free(self.ptr, self.capacity);
+            self.ptr = new_ptr;
+        }
+        self.capacity = capacity;
+        self.head = 0;
+
+        Ok(())
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a RingBuffer<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for RingBuffer<T, A> {
+    fn clone(&self) -> Self {
+        let mut buf: RingBuffer<T, A> =
+            RingBuffer::with_overflow_in(self.capacity, self.overflow, self.alloc.clone());
+
+        for value in self.iter() {
+            unsafe {
+                ptr::write(buf.ptr.add(buf.len), value.clone());
+            }
+            buf.len += 1;
+        }
+
+        buf
+    }
+}
+
+impl<T, A: Allocator> Drop for RingBuffer<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+        self.alloc.free(self.ptr, self.capacity);
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for RingBuffer<T, A> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        if i >= self.len {
+            panic!("array bound!");
+        }
+        unsafe { &(*self.slot(i)) }
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for RingBuffer<T, A> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        if i >= self.len {
+            panic!("array bound!");
+        }
+        unsafe { &mut (*self.slot(i)) }
+    }
+}
+
+impl<T, A: Allocator> fmt::Debug for RingBuffer<T, A>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, A: Allocator> Queue<T> for RingBuffer<T, A> {
+    fn enqueue(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn try_dequeue(&mut self) -> Option<T> {
+        self.try_pop_front()
+    }
+
+    fn try_front(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+}
+
+impl<T, A: Allocator> Deque<T> for RingBuffer<T, A> {
+    fn push_front(&mut self, value: T) {
+        if self.try_push_front(value).is_err() {
+            panic!("this ring buffer is full");
+        }
+    }
+
+    fn push_back(&mut self, value: T) {
+        if self.try_push_back(value).is_err() {
+            panic!("this ring buffer is full");
+        }
+    }
+
+    fn try_pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let value = unsafe { ptr::read(self.slot(0)) };
+        self.head = (self.head + 1) % self.capacity;
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    fn try_pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        unsafe { Some(ptr::read(self.slot(self.len))) }
+    }
+
+    fn try_back(&mut self) -> Option<&mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let len = self.len;
+        self.get_mut(len - 1)
+    }
+}
+
+impl<T, A: Allocator> Stack<T> for RingBuffer<T, A> {
+    fn push(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn try_pop(&mut self) -> Option<T> {
+        self.try_pop_back()
+    }
+
+    fn try_top(&mut self) -> Option<&mut T> {
+        self.try_back()
+    }
+}
@@ -1,12 +1,12 @@
 use std::cmp::Ordering;
-use std::ops::IndexMut;
+use std::ops::{Index, IndexMut, Range};
 
 pub trait Search<K: Copy, V> {
     fn search(&mut self, key: K) -> &mut V;
 }
 
 pub trait Ordered<T: Ord> {
-    fn push(&mut self, value: &T);
+    fn push(&mut self, value: T);
 }
 
 pub fn binary_search<'a, K, E, L, F>(
@@ -23,14 +23,168 @@ where
     L::Output: Sized,
     F: Fn(K, &L::Output) -> Ordering,
 {
-    if lo >= hi {
-        return None;
+    match binary_search_by(&*data, &key, |k, x| cmp(*k, x), lo, hi) {
+        Ok(i) => Some(data.index_mut(i)),
+        Err(_) => None,
+    }
+}
+
+pub fn partition_point<L, P>(data: &L, pred: P, mut lo: usize, mut hi: usize) -> usize
+where
+    L: Index<usize> + ?Sized,
+    P: Fn(&L::Output) -> bool,
+{
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        if pred(&data[mid]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
+pub fn lower_bound<L, K, F>(data: &L, key: &K, cmp: F, lo: usize, hi: usize) -> usize
+where
+    L: Index<usize> + ?Sized,
+    K: ?Sized,
+    F: Fn(&K, &L::Output) -> Ordering,
+{
+    partition_point(data, |x| cmp(key, x) == Ordering::Greater, lo, hi)
+}
+
+pub fn upper_bound<L, K, F>(data: &L, key: &K, cmp: F, lo: usize, hi: usize) -> usize
+where
+    L: Index<usize> + ?Sized,
+    K: ?Sized,
+    F: Fn(&K, &L::Output) -> Ordering,
+{
+    partition_point(data, |x| cmp(key, x) != Ordering::Less, lo, hi)
+}
+
+pub fn equal_range<L, K, F>(data: &L, key: &K, cmp: F, lo: usize, hi: usize) -> Range<usize>
+where
+    L: Index<usize> + ?Sized,
+    K: ?Sized,
+    F: Fn(&K, &L::Output) -> Ordering,
+{
+    let start = lower_bound(data, key, &cmp, lo, hi);
+    let end = upper_bound(data, key, &cmp, start, hi);
+
+    start..end
+}
+
+fn found<L, K, F>(data: &L, key: &K, cmp: F, i: usize, hi: usize) -> Result<usize, usize>
+where
+    L: Index<usize> + ?Sized,
+    K: ?Sized,
+    F: Fn(&K, &L::Output) -> Ordering,
+{
+    if i < hi && cmp(key, &data[i]) == Ordering::Equal {
+        Ok(i)
+    } else {
+        Err(i)
+    }
+}
+
+pub fn binary_search_by<L, K, F>(
+    data: &L,
+    key: &K,
+    cmp: F,
+    lo: usize,
+    hi: usize,
+) -> Result<usize, usize>
+where
+    L: Index<usize> + ?Sized,
+    K: ?Sized,
+    F: Fn(&K, &L::Output) -> Ordering,
+{
+    let i = lower_bound(data, key, &cmp, lo, hi);
+
+    found(data, key, cmp, i, hi)
+}
+
+pub fn exponential_search<L, K, F>(
+    data: &L,
+    key: &K,
+    cmp: F,
+    lo: usize,
+    hi: usize,
+) -> Result<usize, usize>
+where
+    L: Index<usize> + ?Sized,
+    K: ?Sized,
+    F: Fn(&K, &L::Output) -> Ordering,
+{
+    let mut bound = 1;
+
+    while lo + bound <= hi && cmp(key, &data[lo + bound - 1]) == Ordering::Greater {
+        bound *= 2;
+    }
+
+    let start = lo + bound / 2;
+    let end = if lo + bound < hi { lo + bound } else { hi };
+    let i = lower_bound(data, key, &cmp, start, end);
+
+    found(data, key, cmp, i, hi)
+}
+
+pub fn interpolation_search<L, F>(
+    data: &L,
+    key: f64,
+    value: F,
+    mut lo: usize,
+    hi: usize,
+) -> Result<usize, usize>
+where
+    L: Index<usize> + ?Sized,
+    F: Fn(&L::Output) -> f64,
+{
+    let end = hi;
+    let mut hi = hi;
+    let mut bisect = false;
+
+    while lo < hi {
+        let first = value(&data[lo]);
+        if key <= first {
+            break;
+        }
+
+        let last = value(&data[hi - 1]);
+        if key > last {
+            lo = hi;
+            break;
+        }
+
+        let pos = if bisect {
+            lo + (hi - lo) / 2
+        } else {
+            let ratio = (key - first) / (last - first);
+            lo + (ratio * (hi - 1 - lo) as f64) as usize
+        };
+        let pos = if pos <= lo {
+            lo + 1
+        } else if pos >= hi {
+            hi - 1
+        } else {
+            pos
+        };
+
+        let len = hi - lo;
+        if value(&data[pos]) < key {
+            lo = pos + 1;
+        } else {
+            hi = pos;
+        }
+        bisect = (hi - lo) * 2 > len;
     }
 
-    let mid = (lo + hi) / 2;
-    match cmp(key, data.index(mid)) {
-        Ordering::Equal => Some(data.index_mut(mid)),
-        Ordering::Less => binary_search(data, key, cmp, lo, mid),
-        Ordering::Greater => binary_search(data, key, cmp, mid + 1, hi),
+    if lo < end && value(&data[lo]) == key {
+        Ok(lo)
+    } else {
+        Err(lo)
     }
 }
@@ -1,106 +1,414 @@
-use std::iter::FromIterator;
-use std::{cmp, ops};
+pub mod external;
+pub mod linear;
+pub mod parallel;
+
+use super::heap::{self, Heap, PriorityQueue, Sift};
+use super::stack::Stack;
+use super::vector::Vector;
+use std::mem::{self, ManuallyDrop};
+use std::{cmp, ops, ptr, slice};
 
 fn swap<R>(mut a: R, mut b: R)
 where
     R: ops::DerefMut,
-    R::Target: Copy,
+    R::Target: Sized,
+{
+    unsafe {
+        ptr::swap(&mut *a, &mut *b);
+    }
+}
+
+struct MergeHole<I>
+where
+    I: Iterator,
+    I::Item: ops::DerefMut,
+    <I::Item as ops::Deref>::Target: Sized,
+{
+    buf: Vec<ManuallyDrop<<I::Item as ops::Deref>::Target>>,
+    i: usize,
+    mid: usize,
+    j: usize,
+    rest: I,
+}
+
+impl<I> MergeHole<I>
+where
+    I: Iterator,
+    I::Item: ops::DerefMut,
+    <I::Item as ops::Deref>::Target: Sized,
+{
+    fn done(&self) -> bool {
+        self.i >= self.mid && self.j >= self.buf.len()
+    }
+
+    fn fill(&mut self, right: bool) {
+        let k = if right { &mut self.j } else { &mut self.i };
+        let mut n = self.rest.next().unwrap();
+
+        unsafe {
+            ptr::write(&mut *n, ptr::read(&*self.buf[*k]));
+        }
+        *k += 1;
+    }
+}
+
+impl<I> Drop for MergeHole<I>
+where
+    I: Iterator,
+    I::Item: ops::DerefMut,
+    <I::Item as ops::Deref>::Target: Sized,
 {
-    let tmp = *a;
-    *a = *b;
-    *b = tmp;
+    fn drop(&mut self) {
+        while !self.done() {
+            let right = self.i >= self.mid;
+            self.fill(right);
+        }
+    }
 }
 
 fn merge<I, F>(it: I, mid: usize, compare: F)
 where
     I: Iterator + Clone,
     I::Item: ops::DerefMut,
-    <I::Item as ops::Deref>::Target: Copy,
+    <I::Item as ops::Deref>::Target: Sized,
     F: ops::Fn(&<I::Item as ops::Deref>::Target, &<I::Item as ops::Deref>::Target) -> cmp::Ordering,
 {
-    let mut i = 0usize;
-    let mut j = mid + 1;
-    let tmp_data = Vec::from_iter(it.clone().map(|x| *x));
+    let buf: Vec<_> = it
+        .clone()
+        .map(|x| unsafe { ManuallyDrop::new(ptr::read(&*x)) })
+        .collect();
+    let mut hole = MergeHole {
+        mid: cmp::min(mid + 1, buf.len()),
+        j: mid + 1,
+        buf: buf,
+        i: 0,
+        rest: it,
+    };
+
+    while !hole.done() {
+        let right = if hole.i >= hole.mid {
+            true
+        } else if hole.j >= hole.buf.len() {
+            false
+        } else {
+            compare(&hole.buf[hole.i], &hole.buf[hole.j]) == cmp::Ordering::Greater
+        };
+
+        hole.fill(right);
+    }
+}
+
+const INSERTION_CUTOFF: usize = 16;
+const NINTHER_CUTOFF: usize = 128;
+
+struct Window<'a, S: 'a> {
+    data: &'a mut S,
+    lo: usize,
+    len: usize,
+}
+
+impl<'a, S: Sift> Sift for Window<'a, S> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn less(&self, i: usize, j: usize) -> bool {
+        self.data.less(self.lo + i, self.lo + j)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(self.lo + i, self.lo + j);
+    }
+}
+
+fn sift_sort<S: Sift>(data: &mut S) {
+    let len = data.len();
 
-    for mut n in it {
-        if i > mid {
-            *n = tmp_data[j];
-            j += 1;
-        } else if j >= tmp_data.len() {
-            *n = tmp_data[i];
+    for i in (0..len / 2).rev() {
+        heap::sift_down(data, i);
+    }
+    for end in (1..len).rev() {
+        data.swap(0, end);
+        heap::sift_down(
+            &mut Window {
+                data: data,
+                lo: 0,
+                len: end,
+            },
+            0,
+        );
+    }
+}
+
+fn insertion_sort_range<S: Sift>(data: &mut S, lo: usize, hi: usize) {
+    for i in lo + 1..hi {
+        let mut j = i;
+
+        while j > lo && data.less(j, j - 1) {
+            data.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+fn median3<S: Sift>(data: &S, a: usize, b: usize, c: usize) -> usize {
+    if data.less(b, a) {
+        if data.less(c, b) {
+            b
+        } else if data.less(c, a) {
+            c
+        } else {
+            a
+        }
+    } else if data.less(c, a) {
+        a
+    } else if data.less(c, b) {
+        c
+    } else {
+        b
+    }
+}
+
+fn choose_pivot<S: Sift>(data: &S, lo: usize, hi: usize) -> usize {
+    let len = hi - lo;
+    let mid = lo + len / 2;
+
+    if len < NINTHER_CUTOFF {
+        return median3(data, lo, mid, hi - 1);
+    }
+
+    let step = len / 8;
+    let a = median3(data, lo, lo + step, lo + step * 2);
+    let b = median3(data, mid - step, mid, mid + step);
+    let c = median3(data, hi - 1 - step * 2, hi - 1 - step, hi - 1);
+
+    median3(data, a, b, c)
+}
+
+fn partition<S: Sift>(data: &mut S, lo: usize, hi: usize) -> (usize, usize) {
+    let mut lt = lo;
+    let mut gt = hi;
+    let mut i = lo + 1;
+
+    while i < gt {
+        if data.less(i, lt) {
+            data.swap(lt, i);
+            lt += 1;
             i += 1;
-        } else if compare(&tmp_data[i], &tmp_data[j]) == cmp::Ordering::Greater {
-            *n = tmp_data[j];
-            j += 1;
+        } else if data.less(lt, i) {
+            gt -= 1;
+            data.swap(i, gt);
         } else {
-            *n = tmp_data[i];
             i += 1;
         }
     }
+
+    (lt, gt)
 }
 
-fn partition<I, F>(mut it: I, compare: F) -> usize
-where
-    I: Iterator + Clone,
-    I::Item: ops::DerefMut,
-    <I::Item as ops::Deref>::Target: Copy,
-    F: ops::Fn(&<I::Item as ops::Deref>::Target, &<I::Item as ops::Deref>::Target) -> cmp::Ordering,
-{
-    let mut point = it.next().unwrap();
-    let mut it0 = it.clone();
-    let mut next = it0.next().unwrap();
-    let mut count = it.clone().count();
-    let point_value = *point;
-
-    while compare(&point_value, &next) == cmp::Ordering::Greater {
-        swap(&mut *next, &mut *point);
-        count -= 1;
-        point = it.next().unwrap();
-        if let Some(n) = it0.next() {
-            next = n;
+fn intro_sort_rec<S: Sift>(data: &mut S, mut lo: usize, mut hi: usize, mut depth: usize) {
+    loop {
+        let len = hi - lo;
+
+        if len <= INSERTION_CUTOFF {
+            insertion_sort_range(data, lo, hi);
+            return;
+        }
+        if depth == 0 {
+            sift_sort(&mut Window {
+                data: data,
+                lo: lo,
+                len: len,
+            });
+            return;
+        }
+        depth -= 1;
+
+        let pivot = choose_pivot(data, lo, hi);
+        data.swap(lo, pivot);
+        let (lt, gt) = partition(data, lo, hi);
+
+        if lt - lo < hi - gt {
+            intro_sort_rec(data, lo, lt, depth);
+            lo = gt;
         } else {
-            break;
+            intro_sort_rec(data, gt, hi, depth);
+            hi = lt;
         }
     }
+}
+
+fn intro_sort_sift<S: Sift>(data: &mut S) {
+    let len = data.len();
+
+    intro_sort_rec(data, 0, len, depth_limit(len));
+}
+
+fn median_of_medians<S: Sift>(data: &mut S, lo: usize, hi: usize) -> usize {
+    let mut count = 0;
+
+    for start in (lo..hi).step_by(5) {
+        let end = cmp::min(start + 5, hi);
+        insertion_sort_range(data, start, end);
+        data.swap(lo + count, start + (end - start) / 2);
+        count += 1;
+    }
+    select_rec(data, lo, lo + count, lo + count / 2, 0);
 
-    for mut n in it0.clone() {
-        if compare(&point_value, &n) == cmp::Ordering::Greater {
-            swap(&mut *next, &mut *n);
-            swap(&mut *point, &mut *next);
-            count -= 1;
-            point = it.next().unwrap();
-            next = it0.next().unwrap();
+    lo + count / 2
+}
+
+fn select_rec<S: Sift>(data: &mut S, mut lo: usize, mut hi: usize, n: usize, mut depth: usize) {
+    loop {
+        if hi - lo <= INSERTION_CUTOFF {
+            insertion_sort_range(data, lo, hi);
+            return;
+        }
+
+        let pivot = if depth == 0 {
+            median_of_medians(data, lo, hi)
+        } else {
+            depth -= 1;
+            choose_pivot(data, lo, hi)
+        };
+        data.swap(lo, pivot);
+        let (lt, gt) = partition(data, lo, hi);
+
+        if n < lt {
+            hi = lt;
+        } else if n >= gt {
+            lo = gt;
+        } else {
+            return;
         }
     }
+}
+
+fn depth_limit(len: usize) -> usize {
+    2 * (mem::size_of::<usize>() * 8 - len.leading_zeros() as usize)
+}
+
+fn select_sift<S: Sift>(data: &mut S, n: usize) {
+    let len = data.len();
+    if n >= len {
+        panic!("array bound!");
+    }
+
+    select_rec(data, 0, len, n, depth_limit(len));
+}
+
+fn partial_sort_sift<S: Sift>(data: &mut S, k: usize) {
+    let len = data.len();
+    let k = cmp::min(k, len);
+    if k == 0 {
+        return;
+    }
+
+    if k < len {
+        select_rec(data, 0, len, k - 1, depth_limit(len));
+    }
+    intro_sort_rec(data, 0, k, depth_limit(k));
+}
 
-    count
+struct SliceSift<'a, T: 'a, F: 'a> {
+    data: &'a mut [T],
+    compare: &'a F,
 }
 
-fn quick_sort_rec<I, F>(it: I, compare: F, lo: usize, hi: usize)
+impl<'a, T, F> Sift for SliceSift<'a, T, F>
 where
-    I: Iterator + Clone,
-    I::Item: ops::DerefMut,
-    <I::Item as ops::Deref>::Target: Copy,
-    F: ops::Fn(&<I::Item as ops::Deref>::Target, &<I::Item as ops::Deref>::Target) -> cmp::Ordering
-        + Copy,
+    F: Fn(&T, &T) -> cmp::Ordering,
 {
-    if hi - lo <= 1 {
-        return;
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn less(&self, i: usize, j: usize) -> bool {
+        (self.compare)(&self.data[i], &self.data[j]) == cmp::Ordering::Less
     }
 
-    let mid = hi - partition(it.clone().skip(lo).take(hi - lo), compare);
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
+    }
+}
+
+pub fn intro_sort<T, F>(v: &mut [T], compare: F)
+where
+    F: Fn(&T, &T) -> cmp::Ordering,
+{
+    intro_sort_sift(&mut SliceSift {
+        data: v,
+        compare: &compare,
+    });
+}
+
+pub fn select_nth<T, F>(v: &mut [T], n: usize, compare: F)
+where
+    F: Fn(&T, &T) -> cmp::Ordering,
+{
+    select_sift(
+        &mut SliceSift {
+            data: v,
+            compare: &compare,
+        },
+        n,
+    );
+}
+
+pub fn partial_sort<T, F>(v: &mut [T], k: usize, compare: F)
+where
+    F: Fn(&T, &T) -> cmp::Ordering,
+{
+    partial_sort_sift(
+        &mut SliceSift {
+            data: v,
+            compare: &compare,
+        },
+        k,
+    );
+}
 
-    quick_sort_rec(it.clone(), compare, lo, mid - 1);
-    quick_sort_rec(it.clone(), compare, mid, hi);
+struct RefSift<'a, R, F: 'a> {
+    refs: Vec<R>,
+    compare: &'a F,
+}
+
+impl<'a, R, F> Sift for RefSift<'a, R, F>
+where
+    R: ops::DerefMut,
+    R::Target: Sized,
+    F: ops::Fn(&R::Target, &R::Target) -> cmp::Ordering,
+{
+    fn len(&self) -> usize {
+        self.refs.len()
+    }
+
+    fn less(&self, i: usize, j: usize) -> bool {
+        (self.compare)(&self.refs[i], &self.refs[j]) == cmp::Ordering::Less
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        let a: *mut R::Target = &mut *self.refs[i];
+        let b: *mut R::Target = &mut *self.refs[j];
+
+        unsafe {
+            ptr::swap(a, b);
+        }
+    }
 }
 
 pub trait Sort<I>
 where
     I: Iterator + Copy,
     I::Item: ops::DerefMut,
-    <I::Item as ops::Deref>::Target: Copy,
+    <I::Item as ops::Deref>::Target: Sized,
 {
-    fn iter(&mut self) -> I;
+    /// # Safety
+    ///
+    /// Copies of the returned iterator may yield aliasing `&mut` to the same
+    /// element. Callers must not hold two of them at once, nor use any after
+    /// `self` is next borrowed, moved or dropped.
+    unsafe fn iter(&mut self) -> I;
     fn len(&self) -> usize;
 
     fn bubble_sort<F>(&mut self, compare: F)
@@ -110,7 +418,7 @@ where
             &<I::Item as ops::Deref>::Target,
         ) -> cmp::Ordering,
     {
-        let it = self.iter();
+        let it = unsafe { self.iter() };
 
         for len in (2..=self.len()).rev() {
             let it0 = it.take(len - 1);
@@ -131,7 +439,7 @@ where
             &<I::Item as ops::Deref>::Target,
         ) -> cmp::Ordering,
     {
-        let it = self.iter();
+        let it = unsafe { self.iter() };
 
         for (i, mut n) in it.take(self.len() - 1).enumerate() {
             let it0 = it.skip(i + 1);
@@ -150,12 +458,12 @@ where
             &<I::Item as ops::Deref>::Target,
         ) -> cmp::Ordering,
     {
-        let it = self.iter();
+        let it = unsafe { self.iter() };
 
         for (i, mut n) in it.enumerate() {
             let mut it0 = it.take(i);
 
-            while let Some(mut n0) = it0.next() {
+            for mut n0 in it0.by_ref() {
                 if compare(&n0, &n) == cmp::Ordering::Greater {
                     swap(&mut *n0, &mut *n);
                     break;
@@ -177,7 +485,7 @@ where
     {
         let length = self.len();
         let mut h = 1usize;
-        let it = self.iter();
+        let it = unsafe { self.iter() };
 
         while h < length / 3 {
             h = 3 * h + 1;
@@ -189,7 +497,7 @@ where
             for (i, mut n) in it0.clone().enumerate() {
                 let mut it1 = it0.clone().take(i);
 
-                while let Some(mut n0) = it1.next() {
+                for mut n0 in it1.by_ref() {
                     if compare(&n0, &n) == cmp::Ordering::Greater {
                         swap(&mut *n0, &mut *n);
                         break;
@@ -218,7 +526,7 @@ where
             _ => (),
         }
 
-        let it = self.iter();
+        let it = unsafe { self.iter() };
 
         for s in (1..).map(|x| 1 << x).take_while(|x| *x < len * 2) {
             for i in (0..len).step_by(s) {
@@ -234,6 +542,480 @@ where
             &<I::Item as ops::Deref>::Target,
         ) -> cmp::Ordering,
     {
-        quick_sort_rec(self.iter(), &compare, 0, self.len());
+        self.intro_sort(compare);
+    }
+
+    fn intro_sort<F>(&mut self, compare: F)
+    where
+        F: ops::Fn(
+            &<I::Item as ops::Deref>::Target,
+            &<I::Item as ops::Deref>::Target,
+        ) -> cmp::Ordering,
+    {
+        intro_sort_sift(&mut RefSift {
+            refs: unsafe { self.iter() }.collect(),
+            compare: &compare,
+        });
+    }
+
+    fn heap_sort<F>(&mut self, compare: F)
+    where
+        F: ops::Fn(
+            &<I::Item as ops::Deref>::Target,
+            &<I::Item as ops::Deref>::Target,
+        ) -> cmp::Ordering,
+    {
+        sift_sort(&mut RefSift {
+            refs: unsafe { self.iter() }.collect(),
+            compare: &compare,
+        });
+    }
+
+    fn select_nth<F>(&mut self, n: usize, compare: F)
+    where
+        F: ops::Fn(
+            &<I::Item as ops::Deref>::Target,
+            &<I::Item as ops::Deref>::Target,
+        ) -> cmp::Ordering,
+    {
+        select_sift(
+            &mut RefSift {
+                refs: unsafe { self.iter() }.collect(),
+                compare: &compare,
+            },
+            n,
+        );
+    }
+
+    fn partial_sort<F>(&mut self, k: usize, compare: F)
+    where
+        F: ops::Fn(
+            &<I::Item as ops::Deref>::Target,
+            &<I::Item as ops::Deref>::Target,
+        ) -> cmp::Ordering,
+    {
+        partial_sort_sift(
+            &mut RefSift {
+                refs: unsafe { self.iter() }.collect(),
+                compare: &compare,
+            },
+            k,
+        );
+    }
+
+    fn top_k<F>(&mut self, k: usize, compare: F) -> Vector<<I::Item as ops::Deref>::Target>
+    where
+        <I::Item as ops::Deref>::Target: Clone,
+        F: ops::Fn(
+            &<I::Item as ops::Deref>::Target,
+            &<I::Item as ops::Deref>::Target,
+        ) -> cmp::Ordering,
+    {
+        let mut heap = Heap::with_comparator(|a: &I::Item, b: &I::Item| compare(a, b));
+
+        if k > 0 {
+            for n in unsafe { self.iter() } {
+                if heap.size() < k {
+                    heap.insert(n);
+                } else if compare(&n, heap.max()) == cmp::Ordering::Less {
+                    *heap.peek_mut().unwrap() = n;
+                }
+            }
+        }
+
+        let mut top = Vector::with_capacity(heap.size());
+        for n in heap.into_sorted_vector() {
+            top.push((*n).clone());
+        }
+
+        top
+    }
+
+    fn is_sorted_by<F>(&mut self, compare: F) -> bool
+    where
+        F: ops::Fn(
+            &<I::Item as ops::Deref>::Target,
+            &<I::Item as ops::Deref>::Target,
+        ) -> cmp::Ordering,
+    {
+        let it = unsafe { self.iter() };
+
+        it.zip(it.skip(1))
+            .all(|(a, b)| compare(&a, &b) != cmp::Ordering::Greater)
+    }
+}
+
+const MIN_MERGE: usize = 64;
+const MIN_GALLOP: usize = 7;
+
+struct Hole<T> {
+    src: *mut T,
+    end: *mut T,
+    dest: *mut T,
+}
+
+impl<T> Hole<T> {
+    fn len(&self) -> usize {
+        unsafe { self.end.offset_from(self.src) as usize }
+    }
+}
+
+impl<T> Drop for Hole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::copy_nonoverlapping(self.src, self.dest, self.len());
+        }
+    }
+}
+
+fn min_run(mut n: usize) -> usize {
+    let mut r = 0;
+
+    while n >= MIN_MERGE {
+        r |= n & 1;
+        n >>= 1;
+    }
+
+    n + r
+}
+
+fn gallop<P>(len: usize, pred: P) -> usize
+where
+    P: Fn(usize) -> bool,
+{
+    if len == 0 || !pred(0) {
+        return 0;
+    }
+
+    let mut last = 0;
+    let mut ofs = 1;
+    while ofs < len && pred(ofs) {
+        last = ofs;
+        ofs = ofs * 2 + 1;
+    }
+
+    let mut lo = last + 1;
+    let mut hi = cmp::min(ofs, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
+fn count_run<T, F>(v: &mut [T], less: &F) -> usize
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let len = v.len();
+    if len < 2 {
+        return len;
+    }
+
+    let mut end = 2;
+    if less(&v[1], &v[0]) {
+        while end < len && less(&v[end], &v[end - 1]) {
+            end += 1;
+        }
+        v[..end].reverse();
+    } else {
+        while end < len && !less(&v[end], &v[end - 1]) {
+            end += 1;
+        }
+    }
+
+    end
+}
+
+fn binary_insertion_sort<T, F>(v: &mut [T], sorted: usize, less: &F)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    for i in sorted..v.len() {
+        let pos = {
+            let (head, tail) = v.split_at(i);
+            head.len() - gallop(head.len(), |k| less(&tail[0], &head[head.len() - 1 - k]))
+        };
+
+        unsafe {
+            let p = v.as_mut_ptr();
+            let tmp = ptr::read(p.add(i));
+            ptr::copy(p.add(pos), p.add(pos + 1), i - pos);
+            ptr::write(p.add(pos), tmp);
+        }
+    }
+}
+
+unsafe fn merge_lo<T, F>(v: &mut [T], mid: usize, buf: *mut T, less: &F, min_gallop: &mut usize)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let p = v.as_mut_ptr();
+    let right_end = p.add(v.len());
+    let mut right = p.add(mid);
+
+    ptr::copy_nonoverlapping(p, buf, mid);
+    let mut hole = Hole {
+        src: buf,
+        end: buf.add(mid),
+        dest: p,
+    };
+
+    'outer: loop {
+        let mut count_a = 0;
+        let mut count_b = 0;
+
+        loop {
+            if less(&*right, &*hole.src) {
+                ptr::copy_nonoverlapping(right, hole.dest, 1);
+                right = right.add(1);
+                count_a = 0;
+                count_b += 1;
+            } else {
+                ptr::copy_nonoverlapping(hole.src, hole.dest, 1);
+                hole.src = hole.src.add(1);
+                count_a += 1;
+                count_b = 0;
+            }
+            hole.dest = hole.dest.add(1);
+
+            if hole.src == hole.end || right == right_end {
+                break 'outer;
+            }
+            if count_a >= *min_gallop || count_b >= *min_gallop {
+                break;
+            }
+        }
+
+        loop {
+            let n = {
+                let left = slice::from_raw_parts(hole.src, hole.len());
+                gallop(left.len(), |k| !less(&*right, &left[k]))
+            };
+            ptr::copy_nonoverlapping(hole.src, hole.dest, n);
+            hole.src = hole.src.add(n);
+            hole.dest = hole.dest.add(n);
+            if hole.src == hole.end {
+                break 'outer;
+            }
+
+            ptr::copy_nonoverlapping(right, hole.dest, 1);
+            right = right.add(1);
+            hole.dest = hole.dest.add(1);
+            if right == right_end {
+                break 'outer;
+            }
+
+            let m = {
+                let rest = slice::from_raw_parts(right, right_end.offset_from(right) as usize);
+                gallop(rest.len(), |k| less(&rest[k], &*hole.src))
+            };
+            ptr::copy(right, hole.dest, m);
+            right = right.add(m);
+            hole.dest = hole.dest.add(m);
+            if right == right_end {
+                break 'outer;
+            }
+
+            ptr::copy_nonoverlapping(hole.src, hole.dest, 1);
+            hole.src = hole.src.add(1);
+            hole.dest = hole.dest.add(1);
+            if hole.src == hole.end {
+                break 'outer;
+            }
+
+            if n < MIN_GALLOP && m < MIN_GALLOP {
+                *min_gallop += 1;
+                break;
+            }
+            if *min_gallop > 1 {
+                *min_gallop -= 1;
+            }
+        }
+    }
+}
+
+unsafe fn merge_hi<T, F>(v: &mut [T], mid: usize, buf: *mut T, less: &F, min_gallop: &mut usize)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let p = v.as_mut_ptr();
+    let len = v.len() - mid;
+
+    ptr::copy_nonoverlapping(p.add(mid), buf, len);
+    let mut hole = Hole {
+        src: buf,
+        end: buf.add(len),
+        dest: p.add(mid),
+    };
+
+    'outer: loop {
+        let mut count_a = 0;
+        let mut count_b = 0;
+
+        loop {
+            let out = hole.dest.add(hole.len() - 1);
+            let left = hole.dest.sub(1);
+            let right = hole.end.sub(1);
+
+            if less(&*right, &*left) {
+                ptr::copy_nonoverlapping(left, out, 1);
+                hole.dest = left;
+                count_a += 1;
+                count_b = 0;
+            } else {
+                ptr::copy_nonoverlapping(right, out, 1);
+                hole.end = right;
+                count_a = 0;
+                count_b += 1;
+            }
+
+            if hole.dest == p || hole.src == hole.end {
+                break 'outer;
+            }
+            if count_a >= *min_gallop || count_b >= *min_gallop {
+                break;
+            }
+        }
+
+        loop {
+            let n = {
+                let right = &*hole.end.sub(1);
+                let left = slice::from_raw_parts(p, hole.dest.offset_from(p) as usize);
+                gallop(left.len(), |k| less(right, &left[left.len() - 1 - k]))
+            };
+            hole.dest = hole.dest.sub(n);
+            ptr::copy(hole.dest, hole.dest.add(hole.len()), n);
+            if hole.dest == p {
+                break 'outer;
+            }
+
+            hole.end = hole.end.sub(1);
+            ptr::copy_nonoverlapping(hole.end, hole.dest.add(hole.len()), 1);
+            if hole.src == hole.end {
+                break 'outer;
+            }
+
+            let m = {
+                let left = &*hole.dest.sub(1);
+                let right = slice::from_raw_parts(hole.src, hole.len());
+                gallop(right.len(), |k| !less(&right[right.len() - 1 - k], left))
+            };
+            hole.end = hole.end.sub(m);
+            ptr::copy_nonoverlapping(hole.end, hole.dest.add(hole.len()), m);
+            if hole.src == hole.end {
+                break 'outer;
+            }
+
+            hole.dest = hole.dest.sub(1);
+            ptr::copy_nonoverlapping(hole.dest, hole.dest.add(hole.len()), 1);
+            if hole.dest == p {
+                break 'outer;
+            }
+
+            if n < MIN_GALLOP && m < MIN_GALLOP {
+                *min_gallop += 1;
+                break;
+            }
+            if *min_gallop > 1 {
+                *min_gallop -= 1;
+            }
+        }
+    }
+}
+
+fn merge_at<T, F>(v: &mut [T], mid: usize, buf: *mut T, less: &F, min_gallop: &mut usize)
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let len = v.len();
+    let lo = gallop(mid, |k| !less(&v[mid], &v[k]));
+    let hi = len - gallop(len - mid, |k| !less(&v[len - 1 - k], &v[mid - 1]));
+
+    if lo == mid || hi == mid {
+        return;
+    }
+
+    let v = &mut v[lo..hi];
+    let mid = mid - lo;
+    unsafe {
+        if mid <= v.len() - mid {
+            merge_lo(v, mid, buf, less, min_gallop);
+        } else {
+            merge_hi(v, mid, buf, less, min_gallop);
+        }
+    }
+}
+
+fn collapse(runs: &[(usize, usize)], stop: usize) -> Option<usize> {
+    let n = runs.len();
+
+    if n >= 2
+        && (runs[n - 1].0 + runs[n - 1].1 == stop
+            || runs[n - 2].1 <= runs[n - 1].1
+            || (n >= 3 && runs[n - 3].1 <= runs[n - 2].1 + runs[n - 1].1)
+            || (n >= 4 && runs[n - 4].1 <= runs[n - 3].1 + runs[n - 2].1))
+    {
+        if n >= 3 && runs[n - 3].1 < runs[n - 1].1 {
+            Some(n - 3)
+        } else {
+            Some(n - 2)
+        }
+    } else {
+        None
+    }
+}
+
+pub fn tim_sort<T, F>(v: &mut [T], compare: F)
+where
+    F: Fn(&T, &T) -> cmp::Ordering,
+{
+    let len = v.len();
+    if len < 2 || mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    let less = |a: &T, b: &T| compare(a, b) == cmp::Ordering::Less;
+    if len < MIN_MERGE {
+        let run = count_run(v, &less);
+        binary_insertion_sort(v, run, &less);
+        return;
+    }
+
+    let mut buf: Vec<T> = Vec::with_capacity(len / 2);
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    let mut min_gallop = MIN_GALLOP;
+    let min = min_run(len);
+    let mut start = 0;
+
+    while start < len {
+        let mut run = count_run(&mut v[start..], &less);
+        if run < min {
+            let end = cmp::min(start + min, len);
+            binary_insertion_sort(&mut v[start..end], run, &less);
+            run = end - start;
+        }
+        runs.push((start, run));
+        start += run;
+
+        while let Some(i) = collapse(&runs, len) {
+            let (lo, mid) = runs[i];
+            let hi = runs[i + 1].0 + runs[i + 1].1;
+
+            merge_at(
+                &mut v[lo..hi],
+                mid,
+                buf.as_mut_ptr(),
+                &less,
+                &mut min_gallop,
+            );
+            runs[i].1 = hi - lo;
+            runs.remove(i + 1);
+        }
     }
 }
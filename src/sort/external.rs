@@ -0,0 +1,400 @@
+use super::super::heap::{Compare, Heap, PriorityQueue};
+use super::super::stack::Stack;
+use super::super::vector::{IntoIter, Vector};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::iter;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+const DEFAULT_CHUNK: usize = 1 << 16;
+const DEFAULT_FAN_IN: usize = 128;
+
+static RUN_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub trait Codec<T> {
+    fn encode<W: Write>(&self, value: &T, writer: &mut W) -> io::Result<()>;
+    fn decode<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<T>>;
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct LineCodec;
+
+impl Codec<String> for LineCodec {
+    fn encode<W: Write>(&self, value: &String, writer: &mut W) -> io::Result<()> {
+        if value.contains('\n') {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "line contains a newline",
+            ));
+        }
+
+        writer.write_all(value.as_bytes())?;
+        writer.write_all(b"\n")
+    }
+
+    fn decode<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<String>> {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        if line.ends_with('\n') {
+            line.pop();
+        }
+
+        Ok(Some(line))
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct BinaryCodec;
+
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<bool> {
+    let mut filled = 0;
+
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(false),
+            Ok(0) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+            Ok(n) => filled += n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(true)
+}
+
+macro_rules! binary_codec {
+    ($($t: ty)*) => ($(
+        impl Codec<$t> for BinaryCodec {
+            fn encode<W: Write>(&self, value: &$t, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&value.to_le_bytes())
+            }
+
+            fn decode<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<$t>> {
+                let mut buf = [0; ::std::mem::size_of::<$t>()];
+
+                if read_full(reader, &mut buf)? {
+                    Ok(Some(<$t>::from_le_bytes(buf)))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    )*)
+}
+
+binary_codec!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+struct Run {
+    path: PathBuf,
+    reader: Option<BufReader<File>>,
+}
+
+impl Run {
+    fn open<T>(mut self) -> io::Result<Source<T>> {
+        self.reader = Some(BufReader::new(File::open(&self.path)?));
+
+        Ok(Source::File(self))
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        self.reader = None;
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+enum Source<T> {
+    Memory(IntoIter<T>),
+    File(Run),
+}
+
+impl<T> Source<T> {
+    fn next<C: Codec<T>>(&mut self, codec: &C) -> Option<io::Result<T>> {
+        match *self {
+            Source::Memory(ref mut it) => it.next().map(Ok),
+            Source::File(ref mut run) => {
+                match run.reader.as_mut().map_or(Ok(None), |r| codec.decode(r)) {
+                    Ok(Some(value)) => Some(Ok(value)),
+                    Ok(None) => None,
+                    Err(e) => Some(Err(e)),
+                }
+            }
+        }
+    }
+}
+
+struct Entry<T> {
+    value: T,
+    source: usize,
+}
+
+struct Order<F>(F);
+
+impl<T, F> Compare<Entry<T>> for Order<F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn compare(&self, a: &Entry<T>, b: &Entry<T>) -> Ordering {
+        (self.0)(&b.value, &a.value).then(b.source.cmp(&a.source))
+    }
+}
+
+pub struct ExternalSort<T, C: Codec<T>, F: Fn(&T, &T) -> Ordering> {
+    codec: C,
+    compare: F,
+    chunk: usize,
+    fan_in: usize,
+    dir: PathBuf,
+    marker: PhantomData<T>,
+}
+
+impl<T, C: Codec<T>, F: Fn(&T, &T) -> Ordering> ExternalSort<T, C, F> {
+    pub fn new(codec: C, compare: F) -> Self {
+        ExternalSort {
+            codec: codec,
+            compare: compare,
+            chunk: DEFAULT_CHUNK,
+            fan_in: DEFAULT_FAN_IN,
+            dir: env::temp_dir(),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn chunk_size(mut self, records: usize) -> Self {
+        self.chunk = if records == 0 { 1 } else { records };
+        self
+    }
+
+    pub fn fan_in(mut self, runs: usize) -> Self {
+        self.fan_in = if runs < 2 { 2 } else { runs };
+        self
+    }
+
+    pub fn temp_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.dir = dir.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn sort<I>(self, input: I) -> io::Result<Merged<T, C, F>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let (runs, rest) = self.split(input.into_iter().map(Ok))?;
+
+        self.merge(runs, rest)
+    }
+
+    pub fn sort_file<P, Q>(self, input: P, output: Q) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let (runs, rest) = {
+            let mut reader = BufReader::new(File::open(input)?);
+            let codec = &self.codec;
+
+            self.split(iter::from_fn(|| match codec.decode(&mut reader) {
+                Ok(Some(value)) => Some(Ok(value)),
+                Ok(None) => None,
+                Err(e) => Some(Err(e)),
+            }))?
+        };
+
+        let mut merged = self.merge(runs, rest)?;
+        let mut writer = BufWriter::new(File::create(output)?);
+        while let Some(value) = merged.next() {
+            merged.codec.encode(&value?, &mut writer)?;
+        }
+
+        writer.flush()
+    }
+
+    fn split<I>(&self, input: I) -> io::Result<(Vec<Run>, Vector<T>)>
+    where
+        I: Iterator<Item = io::Result<T>>,
+    {
+        let mut runs = Vec::new();
+        let mut chunk = Vector::new();
+
+        for value in input {
+            chunk.push(value?);
+            if chunk.len() == self.chunk {
+                chunk.tim_sort(&self.compare);
+                runs.push(self.spill(chunk.iter().map(Ok))?);
+                chunk.clear();
+            }
+        }
+        chunk.tim_sort(&self.compare);
+
+        Ok((runs, chunk))
+    }
+
+    fn spill<V, I>(&self, values: I) -> io::Result<Run>
+    where
+        V: Borrow<T>,
+        I: Iterator<Item = io::Result<V>>,
+    {
+        let path = self.dir.join(format!(
+            "external-sort-{}-{}.run",
+            process::id(),
+            RUN_ID.fetch_add(1, AtomicOrdering::SeqCst)
+        ));
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        let run = Run {
+            path: path,
+            reader: None,
+        };
+
+        let mut writer = BufWriter::new(file);
+        for value in values {
+            self.codec.encode(value?.borrow(), &mut writer)?;
+        }
+        writer.flush()?;
+
+        Ok(run)
+    }
+
+    fn reduce(&self, mut runs: Vec<Run>, limit: usize) -> io::Result<Vec<Run>> {
+        while runs.len() > limit {
+            let mut merged = Vec::new();
+            let mut rest = runs.into_iter();
+
+            loop {
+                let group: Vec<Run> = rest.by_ref().take(self.fan_in).collect();
+
+                match group.len() {
+                    0 => break,
+                    1 => merged.extend(group),
+                    _ => merged.push(self.merge_runs(group)?),
+                }
+            }
+            runs = merged;
+        }
+
+        Ok(runs)
+    }
+
+    fn merge_runs(&self, runs: Vec<Run>) -> io::Result<Run> {
+        let mut sources = Vec::new();
+        for run in runs {
+            sources.push(run.open()?);
+        }
+
+        let mut heap = Heap::with_comparator(Order(&self.compare));
+        fill(&mut heap, &mut sources, &self.codec)?;
+
+        self.spill(iter::from_fn(|| {
+            if heap.empty() {
+                return None;
+            }
+
+            let entry = heap.del_max();
+            match sources[entry.source].next(&self.codec) {
+                Some(Ok(value)) => heap.insert(Entry {
+                    value: value,
+                    source: entry.source,
+                }),
+                Some(Err(e)) => return Some(Err(e)),
+                None => {}
+            }
+
+            Some(Ok(entry.value))
+        }))
+    }
+
+    fn merge(self, runs: Vec<Run>, rest: Vector<T>) -> io::Result<Merged<T, C, F>> {
+        let limit = if rest.empty() {
+            self.fan_in
+        } else {
+            self.fan_in - 1
+        };
+        let mut sources = Vec::new();
+
+        for run in self.reduce(runs, limit)? {
+            sources.push(run.open()?);
+        }
+        if !rest.empty() {
+            sources.push(Source::Memory(rest.into_iter()));
+        }
+
+        let mut heap = Heap::with_comparator(Order(self.compare));
+        fill(&mut heap, &mut sources, &self.codec)?;
+
+        Ok(Merged {
+            sources: sources,
+            heap: heap,
+            codec: self.codec,
+            error: None,
+        })
+    }
+}
+
+fn fill<T, C, O>(
+    heap: &mut Heap<Entry<T>, O>,
+    sources: &mut [Source<T>],
+    codec: &C,
+) -> io::Result<()>
+where
+    C: Codec<T>,
+    O: Compare<Entry<T>>,
+{
+    for (i, source) in sources.iter_mut().enumerate() {
+        if let Some(value) = source.next(codec) {
+            heap.insert(Entry {
+                value: value?,
+                source: i,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub struct Merged<T, C: Codec<T>, F: Fn(&T, &T) -> Ordering> {
+    sources: Vec<Source<T>>,
+    heap: Heap<Entry<T>, Order<F>>,
+    codec: C,
+    error: Option<io::Error>,
+}
+
+impl<T, C: Codec<T>, F: Fn(&T, &T) -> Ordering> Iterator for Merged<T, C, F> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<io::Result<T>> {
+        if let Some(e) = self.error.take() {
+            while !self.heap.empty() {
+                self.heap.del_max();
+            }
+            self.sources.clear();
+            return Some(Err(e));
+        }
+        if self.heap.empty() {
+            return None;
+        }
+
+        let entry = self.heap.del_max();
+        match self.sources[entry.source].next(&self.codec) {
+            Some(Ok(value)) => self.heap.insert(Entry {
+                value: value,
+                source: entry.source,
+            }),
+            Some(Err(e)) => self.error = Some(e),
+            None => {}
+        }
+
+        Some(Ok(entry.value))
+    }
+}
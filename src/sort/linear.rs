@@ -0,0 +1,276 @@
+use super::super::utility::Allocator;
+use super::super::vector::{Growth, Vector};
+use std::cmp::{self, Ordering};
+use std::{mem, ptr};
+
+const RADIX: usize = 256;
+const MSD_CUTOFF: usize = 32;
+const COUNTING_RANGE: usize = 1 << 16;
+
+pub trait RadixKey: Copy {
+    const BYTES: usize;
+
+    fn byte(&self, i: usize) -> u8;
+}
+
+macro_rules! unsigned_key {
+    ($($t: ty)*) => ($(
+        impl RadixKey for $t {
+            const BYTES: usize = mem::size_of::<$t>();
+
+            fn byte(&self, i: usize) -> u8 {
+                (*self >> ((Self::BYTES - 1 - i) * 8)) as u8
+            }
+        }
+    )*)
+}
+
+macro_rules! signed_key {
+    ($($t: ty, $u: ty);*) => ($(
+        impl RadixKey for $t {
+            const BYTES: usize = mem::size_of::<$t>();
+
+            fn byte(&self, i: usize) -> u8 {
+                (*self as $u ^ 1 << (Self::BYTES * 8 - 1)).byte(i)
+            }
+        }
+    )*)
+}
+
+unsigned_key!(u8 u16 u32 u64 u128 usize);
+signed_key!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128; isize, usize);
+
+impl<const N: usize> RadixKey for [u8; N] {
+    const BYTES: usize = N;
+
+    fn byte(&self, i: usize) -> u8 {
+        self[i]
+    }
+}
+
+fn apply<T>(v: &mut [T], order: &[usize]) {
+    let mut buf: Vec<T> = Vec::with_capacity(v.len());
+
+    unsafe {
+        let src = v.as_mut_ptr();
+        let dst = buf.as_mut_ptr();
+
+        for (i, &j) in order.iter().enumerate() {
+            ptr::copy_nonoverlapping(src.add(j), dst.add(i), 1);
+        }
+        ptr::copy_nonoverlapping(dst, src, v.len());
+    }
+}
+
+fn distribute<B>(order: &[usize], out: &mut [usize], buckets: usize, bucket: B) -> Vec<usize>
+where
+    B: Fn(usize) -> usize,
+{
+    let mut starts = vec![0; buckets + 1];
+
+    for &i in order {
+        starts[bucket(i) + 1] += 1;
+    }
+    for b in 0..buckets {
+        starts[b + 1] += starts[b];
+    }
+
+    let mut next = starts.clone();
+    for &i in order {
+        let b = bucket(i);
+        out[next[b]] = i;
+        next[b] += 1;
+    }
+
+    starts
+}
+
+fn insertion_sort<L>(order: &mut [usize], less: L)
+where
+    L: Fn(usize, usize) -> bool,
+{
+    for i in 1..order.len() {
+        let mut j = i;
+
+        while j > 0 && less(order[j], order[j - 1]) {
+            order.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+fn lsd<K: RadixKey>(keys: &[K]) -> Vec<usize> {
+    let mut items: Vec<(K, usize)> = keys.iter().cloned().zip(0..).collect();
+    let mut tmp = items.clone();
+    let mut counts = vec![[0usize; RADIX]; K::BYTES];
+
+    for key in keys {
+        for d in 0..K::BYTES {
+            counts[d][key.byte(d) as usize] += 1;
+        }
+    }
+
+    for d in (0..K::BYTES).rev() {
+        let count = &mut counts[d];
+        if count.contains(&keys.len()) {
+            continue;
+        }
+
+        let mut sum = 0;
+        for c in count.iter_mut() {
+            sum += *c;
+            *c = sum - *c;
+        }
+        for item in items.iter() {
+            let b = item.0.byte(d) as usize;
+            tmp[count[b]] = *item;
+            count[b] += 1;
+        }
+        mem::swap(&mut items, &mut tmp);
+    }
+
+    items.into_iter().map(|item| item.1).collect()
+}
+
+fn msd<K: RadixKey>(keys: &[K], order: &mut [usize], tmp: &mut [usize], d: usize) {
+    if d == K::BYTES || order.len() < 2 {
+        return;
+    }
+
+    if order.len() <= MSD_CUTOFF {
+        insertion_sort(order, |a, b| {
+            (d..K::BYTES)
+                .map(|k| keys[a].byte(k).cmp(&keys[b].byte(k)))
+                .find(|o| *o != Ordering::Equal)
+                == Some(Ordering::Less)
+        });
+        return;
+    }
+
+    let starts = distribute(order, tmp, RADIX, |i| keys[i].byte(d) as usize);
+    order.copy_from_slice(tmp);
+    for b in 0..RADIX {
+        let (lo, hi) = (starts[b], starts[b + 1]);
+        msd(keys, &mut order[lo..hi], &mut tmp[lo..hi], d + 1);
+    }
+}
+
+fn bucket_of(key: f64, min: f64, scale: f64, buckets: usize) -> usize {
+    if !key.is_finite() {
+        return if key.is_sign_negative() {
+            0
+        } else {
+            buckets - 1
+        };
+    }
+
+    let index = (key / 2.0 - min / 2.0) * scale;
+    if index >= (buckets - 1) as f64 {
+        buckets - 1
+    } else {
+        index as usize
+    }
+}
+
+pub trait LinearSort<T> {
+    fn lsd_radix_sort<K, F>(&mut self, key: F)
+    where
+        K: RadixKey,
+        F: Fn(&T) -> K;
+
+    fn msd_radix_sort<K, F>(&mut self, key: F)
+    where
+        K: RadixKey,
+        F: Fn(&T) -> K;
+
+    fn counting_sort<F>(&mut self, key: F)
+    where
+        F: Fn(&T) -> usize;
+
+    fn bucket_sort<F>(&mut self, key: F)
+    where
+        F: Fn(&T) -> f64;
+}
+
+impl<T, A: Allocator, G: Growth> LinearSort<T> for Vector<T, A, G> {
+    fn lsd_radix_sort<K, F>(&mut self, key: F)
+    where
+        K: RadixKey,
+        F: Fn(&T) -> K,
+    {
+        let keys: Vec<K> = self.iter().map(&key).collect();
+
+        apply(self, &lsd(&keys));
+    }
+
+    fn msd_radix_sort<K, F>(&mut self, key: F)
+    where
+        K: RadixKey,
+        F: Fn(&T) -> K,
+    {
+        let keys: Vec<K> = self.iter().map(&key).collect();
+        let mut order: Vec<usize> = (0..keys.len()).collect();
+        let mut tmp = vec![0; keys.len()];
+
+        msd(&keys, &mut order, &mut tmp, 0);
+        apply(self, &order);
+    }
+
+    fn counting_sort<F>(&mut self, key: F)
+    where
+        F: Fn(&T) -> usize,
+    {
+        let keys: Vec<usize> = self.iter().map(&key).collect();
+        let min = match keys.iter().min() {
+            Some(min) => *min,
+            None => return,
+        };
+        let max = *keys.iter().max().unwrap();
+        if max - min >= cmp::max(COUNTING_RANGE, 2 * keys.len()) {
+            apply(self, &lsd(&keys));
+            return;
+        }
+
+        let order: Vec<usize> = (0..keys.len()).collect();
+        let mut out = vec![0; keys.len()];
+
+        distribute(&order, &mut out, max - min + 1, |i| keys[i] - min);
+        apply(self, &out);
+    }
+
+    fn bucket_sort<F>(&mut self, key: F)
+    where
+        F: Fn(&T) -> f64,
+    {
+        let keys: Vec<f64> = self.iter().map(&key).collect();
+        let len = keys.len();
+        if len < 2 {
+            return;
+        }
+
+        let finite = keys.iter().filter(|k| k.is_finite());
+        let min = finite.clone().fold(f64::INFINITY, |a, &b| a.min(b));
+        let max = finite.fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+        let scale = if max > min {
+            (len - 1) as f64 / (max / 2.0 - min / 2.0)
+        } else {
+            0.0
+        };
+
+        let order: Vec<usize> = (0..len).collect();
+        let mut out = vec![0; len];
+        let starts = distribute(&order, &mut out, len, |i| {
+            bucket_of(keys[i], min, scale, len)
+        });
+        for b in 0..len {
+            let bucket = &mut out[starts[b]..starts[b + 1]];
+
+            if bucket.len() <= MSD_CUTOFF {
+                insertion_sort(bucket, |x, y| keys[x].total_cmp(&keys[y]) == Ordering::Less);
+            } else {
+                super::tim_sort(bucket, |&x, &y| keys[x].total_cmp(&keys[y]));
+            }
+        }
+        apply(self, &out);
+    }
+}
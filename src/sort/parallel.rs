@@ -0,0 +1,151 @@
+use super::super::utility::Allocator;
+use super::super::vector::{Growth, Vector};
+use super::{choose_pivot, merge_at, partition, SliceSift, MIN_GALLOP};
+use std::cmp::Ordering;
+use std::ops::DerefMut;
+use std::thread;
+
+const DEFAULT_CUTOFF: usize = 1 << 13;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parallelism {
+    workers: usize,
+    cutoff: usize,
+}
+
+impl Parallelism {
+    pub fn new(workers: usize, cutoff: usize) -> Self {
+        Parallelism {
+            workers: if workers == 0 { 1 } else { workers },
+            cutoff: cutoff,
+        }
+    }
+
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+
+    pub fn cutoff(&self) -> usize {
+        self.cutoff
+    }
+}
+
+impl Default for Parallelism {
+    fn default() -> Self {
+        let workers = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+
+        Parallelism::new(workers, DEFAULT_CUTOFF)
+    }
+}
+
+fn merge_sort_rec<T, F>(v: &mut [T], workers: usize, cutoff: usize, compare: &F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let len = v.len();
+    if workers <= 1 || len <= cutoff || len < 2 {
+        super::tim_sort(v, compare);
+        return;
+    }
+
+    let mid = len / 2;
+    {
+        let (left, right) = v.split_at_mut(mid);
+        thread::scope(|s| {
+            s.spawn(|| merge_sort_rec(left, workers / 2, cutoff, compare));
+            merge_sort_rec(right, workers - workers / 2, cutoff, compare);
+        });
+    }
+
+    let mut buf: Vec<T> = Vec::with_capacity(len / 2);
+    let mut min_gallop = MIN_GALLOP;
+    merge_at(
+        v,
+        mid,
+        buf.as_mut_ptr(),
+        &|a: &T, b: &T| compare(a, b) == Ordering::Less,
+        &mut min_gallop,
+    );
+}
+
+fn quick_sort_rec<T, F>(v: &mut [T], workers: usize, cutoff: usize, compare: &F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let len = v.len();
+    if workers <= 1 || len <= cutoff || len < 2 {
+        super::intro_sort(v, compare);
+        return;
+    }
+
+    let (lt, gt) = {
+        let mut data = SliceSift {
+            data: &mut *v,
+            compare: compare,
+        };
+        let pivot = choose_pivot(&data, 0, len);
+        data.data.swap(0, pivot);
+        partition(&mut data, 0, len)
+    };
+
+    let (left, rest) = v.split_at_mut(lt);
+    let right = &mut rest[gt - lt..];
+    thread::scope(|s| {
+        s.spawn(|| quick_sort_rec(left, workers / 2, cutoff, compare));
+        quick_sort_rec(right, workers - workers / 2, cutoff, compare);
+    });
+}
+
+pub trait ParallelSort<T: Send> {
+    fn par_merge_sort_with<F>(&mut self, parallelism: Parallelism, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync;
+
+    fn par_quick_sort_with<F>(&mut self, parallelism: Parallelism, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync;
+
+    fn par_merge_sort<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        self.par_merge_sort_with(Parallelism::default(), compare);
+    }
+
+    fn par_quick_sort<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        self.par_quick_sort_with(Parallelism::default(), compare);
+    }
+}
+
+impl<T: Send, A: Allocator, G: Growth> ParallelSort<T> for Vector<T, A, G> {
+    fn par_merge_sort_with<F>(&mut self, parallelism: Parallelism, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        merge_sort_rec(
+            self.deref_mut(),
+            parallelism.workers,
+            parallelism.cutoff,
+            &compare,
+        );
+    }
+
+    fn par_quick_sort_with<F>(&mut self, parallelism: Parallelism, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        quick_sort_rec(
+            self.deref_mut(),
+            parallelism.workers,
+            parallelism.cutoff,
+            &compare,
+        );
+    }
+}
@@ -1,5 +1,13 @@
 pub trait Stack<T> {
-    fn push(&mut self, value: &T);
-    fn pop(&mut self) -> T;
-    fn top(&mut self) -> &mut T;
+    fn push(&mut self, value: T);
+    fn try_pop(&mut self) -> Option<T>;
+    fn try_top(&mut self) -> Option<&mut T>;
+
+    fn pop(&mut self) -> T {
+        self.try_pop().expect("this stack is empty")
+    }
+
+    fn top(&mut self) -> &mut T {
+        self.try_top().expect("this stack is empty")
+    }
 }
@@ -1,21 +1,93 @@
-use std::alloc::{alloc, dealloc, Layout, LayoutErr};
-use std::mem::{align_of, size_of};
-use std::ptr;
+use std::alloc::{self, Layout, LayoutError};
+use std::ptr::{self, NonNull};
 
-pub fn malloc<T>(capacity: usize) -> Result<*mut T, LayoutErr> {
-    let layout = Layout::from_size_align(capacity * size_of::<T>(), align_of::<T>())?;
-    unsafe { Ok(alloc(layout) as *mut T) }
+#[derive(Debug, Clone, PartialEq)]
+pub enum AllocErr {
+    Layout(LayoutError),
+    CapacityOverflow,
+    OutOfMemory,
 }
 
-pub fn malloc_val<T>(value: &T) -> *mut T {
-    let layout = Layout::for_value(value);
-    let ptr = unsafe { alloc(layout) as *mut T };
-    unsafe { *ptr = ptr::read(value as *const T) };
-    ptr
+impl From<LayoutError> for AllocErr {
+    fn from(err: LayoutError) -> Self {
+        AllocErr::Layout(err)
+    }
 }
 
-pub fn free<T>(ptr: *mut T, capacity: usize) -> Result<(), LayoutErr> {
-    let layout = Layout::from_size_align(capacity * size_of::<T>(), align_of::<T>())?;
-    unsafe { dealloc(ptr as *mut u8, layout) };
-    Ok(())
-}
\ No newline at end of file
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexErr {
+    OutOfBounds { index: usize, len: usize },
+    InvalidRange { lo: usize, hi: usize },
+    Alloc(AllocErr),
+}
+
+impl From<AllocErr> for IndexErr {
+    fn from(err: AllocErr) -> Self {
+        IndexErr::Alloc(err)
+    }
+}
+
+pub trait Allocator {
+    /// # Safety
+    ///
+    /// `layout` must have a non-zero size. The result is null on failure.
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8;
+
+    /// # Safety
+    ///
+    /// `ptr` must come from `alloc` on this allocator with the same `layout`,
+    /// and must not be freed twice.
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout);
+
+    fn malloc<T>(&self, capacity: usize) -> Result<*mut T, AllocErr> {
+        let layout = Layout::array::<T>(capacity)?;
+
+        if layout.size() == 0 {
+            return Ok(NonNull::dangling().as_ptr());
+        }
+
+        let ptr = unsafe { self.alloc(layout) as *mut T };
+        if ptr.is_null() {
+            return Err(AllocErr::OutOfMemory);
+        }
+
+        Ok(ptr)
+    }
+
+    fn malloc_val<T>(&self, value: T) -> Result<*mut T, AllocErr> {
+        let ptr = self.malloc(1)?;
+        unsafe { ptr::write(ptr, value) };
+        Ok(ptr)
+    }
+
+    fn free<T>(&self, ptr: *mut T, capacity: usize) {
+        if let Ok(layout) = Layout::array::<T>(capacity) {
+            if layout.size() != 0 {
+                unsafe { self.dealloc(ptr as *mut u8, layout) };
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct Global;
+
+impl Allocator for Global {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        alloc::alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        alloc::dealloc(ptr, layout)
+    }
+}
+
+impl<A: Allocator> Allocator for &A {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        (**self).alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        (**self).dealloc(ptr, layout)
+    }
+}
@@ -1,25 +1,66 @@
 use super::search::Ordered;
+use super::sort;
 pub use super::sort::Sort;
 use super::stack::Stack;
-use super::utility::{free, malloc};
-use std::cmp::PartialEq;
+use super::utility::{AllocErr, Allocator, Global, IndexErr};
+use std::cmp::{self, PartialEq};
 use std::fmt;
-use std::iter::DoubleEndedIterator;
+use std::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, IntoIterator};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::mem;
 use std::ptr;
 
 type Rank = usize;
 const DEFAULT_CAPACITY: usize = 8;
 
-#[derive(Clone, Copy)]
-pub struct Iter<'a, T: 'a> {
+fn advance<T>(ptr: *mut T, n: usize) -> *mut T {
+    if mem::size_of::<T>() == 0 {
+        (ptr as usize).wrapping_add(n) as *mut T
+    } else {
+        unsafe { ptr.add(n) }
+    }
+}
+
+fn retreat<T>(ptr: *mut T) -> *mut T {
+    if mem::size_of::<T>() == 0 {
+        (ptr as usize).wrapping_sub(1) as *mut T
+    } else {
+        unsafe { ptr.sub(1) }
+    }
+}
+
+fn distance<T>(ptr: *mut T, end: *mut T) -> usize {
+    if mem::size_of::<T>() == 0 {
+        (end as usize).wrapping_sub(ptr as usize)
+    } else {
+        unsafe { end.offset_from(ptr) as usize }
+    }
+}
+
+fn element<T>(ptr: *mut T) -> *mut T {
+    if mem::size_of::<T>() == 0 {
+        ptr::NonNull::dangling().as_ptr()
+    } else {
+        ptr
+    }
+}
+
+pub struct RawIter<'a, T: 'a> {
     ptr: *mut T,
     end: *mut T,
     marker: PhantomData<&'a mut T>,
 }
 
-impl<'a, T: 'a> Iterator for Iter<'a, T> {
+impl<'a, T: 'a> Clone for RawIter<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: 'a> Copy for RawIter<'a, T> {}
+
+impl<'a, T: 'a> Iterator for RawIter<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -28,13 +69,63 @@ impl<'a, T: 'a> Iterator for Iter<'a, T> {
         }
 
         unsafe {
-            let ptr = self.ptr;
-            self.ptr = self.ptr.add(1);
+            let ptr = element(self.ptr);
+            self.ptr = advance(self.ptr, 1);
             Some(&mut (*ptr))
         }
     }
 }
 
+impl<'a, T: 'a> DoubleEndedIterator for RawIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.end == self.ptr {
+            return None;
+        }
+
+        unsafe {
+            self.end = retreat(self.end);
+            Some(&mut (*element(self.end)))
+        }
+    }
+}
+
+pub struct Iter<'a, T: 'a> {
+    ptr: *mut T,
+    end: *mut T,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: 'a> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            ptr: self.ptr,
+            end: self.end,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ptr == self.end {
+            return None;
+        }
+
+        unsafe {
+            let ptr = element(self.ptr);
+            self.ptr = advance(self.ptr, 1);
+            Some(&(*ptr))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = distance(self.ptr, self.end);
+        (len, Some(len))
+    }
+}
+
 impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.end == self.ptr {
@@ -42,39 +133,264 @@ impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
         }
 
         unsafe {
-            self.end = self.end.sub(1);
-            Some(&mut (*self.end))
+            self.end = retreat(self.end);
+            Some(&(*element(self.end)))
+        }
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for Iter<'a, T> {}
+
+pub struct IterMut<'a, T: 'a> {
+    ptr: *mut T,
+    end: *mut T,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ptr == self.end {
+            return None;
+        }
+
+        unsafe {
+            let ptr = element(self.ptr);
+            self.ptr = advance(self.ptr, 1);
+            Some(&mut (*ptr))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = distance(self.ptr, self.end);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.end == self.ptr {
+            return None;
+        }
+
+        unsafe {
+            self.end = retreat(self.end);
+            Some(&mut (*element(self.end)))
+        }
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for IterMut<'a, T> {}
+
+pub struct IntoIter<T, A: Allocator = Global> {
+    buf: *mut T,
+    capacity: usize,
+    ptr: *mut T,
+    end: *mut T,
+    alloc: A,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.ptr == self.end {
+            return None;
+        }
+
+        unsafe {
+            let ptr = element(self.ptr);
+            self.ptr = advance(self.ptr, 1);
+            Some(ptr::read(ptr))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = distance(self.ptr, self.end);
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.end == self.ptr {
+            return None;
+        }
+
+        unsafe {
+            self.end = retreat(self.end);
+            Some(ptr::read(element(self.end)))
         }
     }
 }
 
-pub struct Vector<T> {
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+        self.alloc.free(self.buf, self.capacity);
+    }
+}
+
+pub trait Growth {
+    fn grow(&self, capacity: usize) -> usize;
+    fn shrink(&self, capacity: usize, len: usize) -> Option<usize>;
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Doubling;
+
+impl Growth for Doubling {
+    fn grow(&self, capacity: usize) -> usize {
+        if capacity == 0 {
+            return DEFAULT_CAPACITY;
+        }
+
+        capacity * 2
+    }
+
+    fn shrink(&self, capacity: usize, len: usize) -> Option<usize> {
+        if capacity < 2 * DEFAULT_CAPACITY || len * 4 > capacity {
+            return None;
+        }
+
+        Some(capacity / 2)
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct OneAndHalf;
+
+impl Growth for OneAndHalf {
+    fn grow(&self, capacity: usize) -> usize {
+        if capacity < DEFAULT_CAPACITY {
+            return DEFAULT_CAPACITY;
+        }
+
+        capacity + capacity / 2
+    }
+
+    fn shrink(&self, capacity: usize, len: usize) -> Option<usize> {
+        if capacity < 2 * DEFAULT_CAPACITY || len * 9 > capacity * 4 {
+            return None;
+        }
+
+        Some(capacity * 2 / 3)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Increment(pub usize);
+
+impl Growth for Increment {
+    fn grow(&self, capacity: usize) -> usize {
+        capacity + self.0
+    }
+
+    fn shrink(&self, capacity: usize, len: usize) -> Option<usize> {
+        if self.0 == 0 || capacity - len < 2 * self.0 {
+            return None;
+        }
+
+        Some(capacity - self.0)
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct NoShrink<G: Growth>(pub G);
+
+impl<G: Growth> Growth for NoShrink<G> {
+    fn grow(&self, capacity: usize) -> usize {
+        self.0.grow(capacity)
+    }
+
+    fn shrink(&self, _: usize, _: usize) -> Option<usize> {
+        None
+    }
+}
+
+pub struct Vector<T, A: Allocator = Global, G: Growth = Doubling> {
     len: Rank,
     capacity: usize,
     ptr: *mut T,
+    alloc: A,
+    growth: G,
 }
 
 impl<T> Vector<T> {
     pub fn new() -> Vector<T> {
-        Vector {
-            ptr: malloc(DEFAULT_CAPACITY).unwrap(),
-            len: 0 as Rank,
-            capacity: DEFAULT_CAPACITY,
-        }
+        Vector::new_in(Global)
     }
 
+    pub fn with_capacity(capacity: usize) -> Vector<T> {
+        Vector::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T: Clone> Vector<T> {
     pub fn from_slice(slice: &[T]) -> Vector<T> {
-        let new_ptr = malloc(slice.len()).unwrap();
-        unsafe {
-            ptr::copy(slice.as_ptr(), new_ptr, slice.len());
+        Vector::from_slice_in(slice, Global)
+    }
+}
+
+impl<T: Clone, A: Allocator> Vector<T, A> {
+    pub fn from_slice_in(slice: &[T], alloc: A) -> Vector<T, A> {
+        let mut vec: Vector<T, A> = Vector::with_capacity_in(slice.len(), alloc);
+
+        for (i, value) in slice.iter().enumerate() {
+            unsafe {
+                ptr::write(vec.ptr.add(i), value.clone());
+            }
+            vec.len += 1;
         }
+
+        vec
+    }
+}
+
+impl<T, A: Allocator> Vector<T, A> {
+    pub fn new_in(alloc: A) -> Vector<T, A> {
+        Vector::with_capacity_in(DEFAULT_CAPACITY, alloc)
+    }
+
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Vector<T, A> {
+        Vector::with_growth_in(capacity, alloc, Doubling)
+    }
+}
+
+impl<T, G: Growth> Vector<T, Global, G> {
+    pub fn with_growth(growth: G) -> Vector<T, Global, G> {
+        Vector::with_growth_in(DEFAULT_CAPACITY, Global, growth)
+    }
+}
+
+impl<T, A: Allocator, G: Growth> Vector<T, A, G> {
+    pub fn with_growth_in(capacity: usize, alloc: A, growth: G) -> Vector<T, A, G> {
         Vector {
-            ptr: new_ptr,
-            len: slice.len(),
-            capacity: slice.len(),
+            ptr: alloc.malloc(capacity).expect("allocation failed"),
+            len: 0 as Rank,
+            capacity: capacity,
+            alloc: alloc,
+            growth: growth,
         }
     }
 
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    pub fn growth(&self) -> &G {
+        &self.growth
+    }
+
     pub fn capacity(&self) -> usize {
         self.capacity
     }
@@ -96,56 +412,162 @@ impl<T> Vector<T> {
         }
     }
 
-    pub fn insert(&mut self, rank: Rank, value: &T) {
-        self.expand();
-        self.len += 1;
-        for i in (rank..(self.len - 1)).rev() {
-            unsafe {
-                self[i + 1] = ptr::read(&self[i]);
-            }
+    pub fn get(&self, i: Rank) -> Option<&T> {
+        if i >= self.len {
+            return None;
         }
+        unsafe { Some(&(*(self.ptr.add(i)))) }
+    }
+
+    pub fn get_mut(&mut self, i: Rank) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+        unsafe { Some(&mut (*(self.ptr.add(i)))) }
+    }
+
+    pub fn insert(&mut self, rank: Rank, value: T) {
+        match self.try_insert(rank, value) {
+            Err(IndexErr::Alloc(_)) => panic!("allocation failed"),
+            Err(_) => panic!("array bound!"),
+            Ok(()) => (),
+        }
+    }
+
+    pub fn try_insert(&mut self, rank: Rank, value: T) -> Result<(), IndexErr> {
+        if rank > self.len {
+            return Err(IndexErr::OutOfBounds {
+                index: rank,
+                len: self.len,
+            });
+        }
+
+        self.expand()?;
         unsafe {
-            self[rank] = ptr::read(value);
+            ptr::copy(self.ptr.add(rank), self.ptr.add(rank + 1), self.len - rank);
+            ptr::write(self.ptr.add(rank), value);
         }
+        self.len += 1;
+
+        Ok(())
     }
 
-    pub fn remove(&mut self, mut lo: Rank, mut hi: Rank) {
-        let size = hi - lo;
-        while hi < self.len {
-            unsafe {
-                self[lo] = ptr::read(&self[hi]);
-            }
-            lo += 1;
-            hi += 1;
+    pub fn remove(&mut self, lo: Rank, hi: Rank) {
+        if self.try_remove(lo, hi).is_err() {
+            panic!("array bound!");
         }
-        self.shrink();
-        self.len -= size;
     }
 
-    fn expand(&mut self) {
-        if (self.len as usize) < self.capacity {
-            return;
+    pub fn try_remove(&mut self, lo: Rank, hi: Rank) -> Result<(), IndexErr> {
+        if lo > hi {
+            return Err(IndexErr::InvalidRange { lo: lo, hi: hi });
+        }
+        if hi > self.len {
+            return Err(IndexErr::OutOfBounds {
+                index: hi,
+                len: self.len,
+            });
         }
+
+        let len = self.len;
+
+        self.len = lo;
         unsafe {
-            let new_ptr = malloc(self.capacity * 2).unwrap();
-            ptr::copy(self.ptr, new_ptr, self.len);
-            free(self.ptr, self.capacity).expect("vector.rs 123 error when free\n");
-            self.ptr = new_ptr;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.add(lo), hi - lo));
+            ptr::copy(self.ptr.add(hi), self.ptr.add(lo), len - hi);
         }
-        self.capacity *= 2;
+        self.len = len - (hi - lo);
+        let _ = self.shrink();
+
+        Ok(())
     }
 
-    fn shrink(&mut self) {
-        if self.capacity < 2 * DEFAULT_CAPACITY || self.len * 4 > self.capacity {
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
             return;
         }
+
+        let size = self.len - len;
+
+        self.len = len;
         unsafe {
-            let new_ptr = malloc(self.capacity / 2).unwrap();
-            ptr::copy(self.ptr, new_ptr, self.len);
-            free(self.ptr, self.capacity).unwrap();
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.add(len), size));
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).expect("allocation failed");
+    }
+
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocErr> {
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or(AllocErr::CapacityOverflow)?;
+
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        let capacity = cmp::max(required, self.growth.grow(self.capacity));
+        self.resize(capacity)
+    }
+
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.try_reserve_exact(additional).expect("allocation failed");
+    }
+
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), AllocErr> {
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or(AllocErr::CapacityOverflow)?;
+
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        self.resize(required)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity > self.len {
+            let len = self.len;
+            self.resize(len).expect("allocation failed");
+        }
+    }
+
+    fn resize(&mut self, capacity: usize) -> Result<(), AllocErr> {
+        unsafe {
+            let new_ptr = self.alloc.malloc(capacity)?;
+            ptr::copy_nonoverlapping(self.ptr, new_ptr, self.len);
+            self.alloc.free(self.ptr, self.capacity);
             self.ptr = new_ptr;
         }
-        self.capacity /= 2;
+        self.capacity = capacity;
+
+        Ok(())
+    }
+
+    fn expand(&mut self) -> Result<(), AllocErr> {
+        if self.len < self.capacity {
+            return Ok(());
+        }
+
+        let capacity = cmp::max(self.len + 1, self.growth.grow(self.capacity));
+        self.resize(capacity)
+    }
+
+    fn shrink(&mut self) -> Result<(), AllocErr> {
+        if let Some(capacity) = self.growth.shrink(self.capacity, self.len) {
+            return self.resize(cmp::max(capacity, self.len));
+        }
+
+        Ok(())
     }
 
     pub fn swap(&mut self, i: Rank, j: Rank) {
@@ -155,49 +577,108 @@ impl<T> Vector<T> {
     }
 }
 
-impl<T: PartialEq> Vector<T> {
+impl<T: PartialEq, A: Allocator, G: Growth> Vector<T, A, G> {
     pub fn find(&self, e: &T) -> Option<Rank> {
-        for i in 0..self.len {
-            if self[i] == *e {
-                return Option::Some(i);
-            }
-        }
-        Option::None
+        (0..self.len).find(|&i| self[i] == *e)
     }
 }
 
-impl<'a, T: 'a> Vector<T> {
-    pub fn iter(&mut self) -> Iter<'a, T> {
+impl<T, A: Allocator, G: Growth> Vector<T, A, G> {
+    pub fn iter<'a>(&'a self) -> Iter<'a, T> {
         Iter {
             ptr: self.ptr,
-            end: unsafe { self.ptr.add(self.len) },
+            end: advance(self.ptr, self.len),
             marker: PhantomData,
         }
     }
+
+    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, T> {
+        IterMut {
+            ptr: self.ptr,
+            end: advance(self.ptr, self.len),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator, G: Growth> IntoIterator for Vector<T, A, G> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> IntoIter<T, A> {
+        let iter = IntoIter {
+            buf: self.ptr,
+            capacity: self.capacity,
+            ptr: self.ptr,
+            end: advance(self.ptr, self.len),
+            alloc: unsafe { ptr::read(&self.alloc) },
+        };
+        mem::forget(self);
+
+        iter
+    }
+}
+
+impl<'a, T, A: Allocator, G: Growth> IntoIterator for &'a Vector<T, A, G> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator, G: Growth> IntoIterator for &'a mut Vector<T, A, G> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
 }
 
-impl<T> Clone for Vector<T> {
+impl<T: Clone, A: Allocator + Clone, G: Growth + Clone> Clone for Vector<T, A, G> {
     fn clone(&self) -> Self {
-        let new_ptr = malloc(self.capacity).unwrap();
+        let ptr: *mut T = self.alloc.malloc(self.capacity).expect("allocation failed");
+        let mut vec = Vector {
+            ptr: ptr,
+            len: 0,
+            capacity: self.capacity,
+            alloc: self.alloc.clone(),
+            growth: self.growth.clone(),
+        };
 
-        unsafe {
-            ptr::copy(self.ptr, new_ptr, self.len);
+        for i in 0..self.len {
+            unsafe {
+                ptr::write(vec.ptr.add(i), self[i].clone());
+            }
+            vec.len += 1;
         }
-        Vector {
-            ptr: new_ptr,
-            len: self.len,
-            capacity: self.capacity,
+
+        vec
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
+
+        for i in 0..self.len {
+            self[i].clone_from(&source[i]);
+        }
+
+        for i in self.len..source.len {
+            self.insert(i, source[i].clone());
         }
     }
 }
 
-impl<T> Drop for Vector<T> {
+impl<T, A: Allocator, G: Growth> Drop for Vector<T, A, G> {
     fn drop(&mut self) {
-        free(self.ptr, self.capacity).unwrap();
+        self.clear();
+        self.alloc.free(self.ptr, self.capacity);
     }
 }
 
-impl<T> Index<Rank> for Vector<T> {
+impl<T, A: Allocator, G: Growth> Index<Rank> for Vector<T, A, G> {
     type Output = T;
 
     fn index(&self, i: Rank) -> &T {
@@ -208,7 +689,7 @@ impl<T> Index<Rank> for Vector<T> {
     }
 }
 
-impl<T> IndexMut<Rank> for Vector<T> {
+impl<T, A: Allocator, G: Growth> IndexMut<Rank> for Vector<T, A, G> {
     fn index_mut(&mut self, i: Rank) -> &mut T {
         if i >= self.len {
             panic!("array bound!");
@@ -217,7 +698,7 @@ impl<T> IndexMut<Rank> for Vector<T> {
     }
 }
 
-impl<T> Deref for Vector<T> {
+impl<T, A: Allocator, G: Growth> Deref for Vector<T, A, G> {
     type Target = [T];
 
     fn deref(&self) -> &[T] {
@@ -225,17 +706,21 @@ impl<T> Deref for Vector<T> {
     }
 }
 
-impl<T> DerefMut for Vector<T> {
+impl<T, A: Allocator, G: Growth> DerefMut for Vector<T, A, G> {
     fn deref_mut(&mut self) -> &mut [T] {
         unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
     }
 }
 
-impl<T> PartialEq for Vector<T>
+impl<T, A, B, G, H> PartialEq<Vector<T, B, H>> for Vector<T, A, G>
 where
     T: PartialEq,
+    A: Allocator,
+    B: Allocator,
+    G: Growth,
+    H: Growth,
 {
-    fn eq(&self, other: &Vector<T>) -> bool {
+    fn eq(&self, other: &Vector<T, B, H>) -> bool {
         if self.len != other.len {
             return false;
         }
@@ -248,7 +733,7 @@ where
     }
 }
 
-impl<T> fmt::Debug for Vector<T>
+impl<T, A: Allocator, G: Growth> fmt::Debug for Vector<T, A, G>
 where
     T: fmt::Debug,
 {
@@ -267,51 +752,86 @@ where
     }
 }
 
-impl<T> Stack<T> for Vector<T> {
-    fn push(&mut self, value: &T) {
+impl<T, A: Allocator, G: Growth> Stack<T> for Vector<T, A, G> {
+    fn push(&mut self, value: T) {
         let size = self.len();
         self.insert(size, value);
     }
 
-    fn pop(&mut self) -> T {
+    fn try_pop(&mut self) -> Option<T> {
         let size = self.len();
 
         if size == 0 {
-            panic!("this stack is empty");
+            return None;
         }
 
         unsafe {
             let out = ptr::read(&self[size - 1]);
 
-            self.remove(size - 1, size);
+            self.len -= 1;
+            let _ = self.shrink();
 
-            out
+            Some(out)
         }
     }
 
-    fn top(&mut self) -> &mut T {
+    fn try_top(&mut self) -> Option<&mut T> {
         let size = self.len();
 
         if size == 0 {
-            panic!("this stack is empty");
+            return None;
         }
 
-        self.index_mut(size - 1)
+        self.get_mut(size - 1)
     }
 }
 
-impl<'a, T: 'a + Copy> Sort<Iter<'a, T>> for Vector<T> {
+impl<T, A: Allocator, G: Growth> Vector<T, A, G> {
+    pub fn tim_sort<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> cmp::Ordering,
+    {
+        sort::tim_sort(self.deref_mut(), compare);
+    }
+}
+
+impl<'a, T: 'a, A: Allocator, G: Growth> Sort<RawIter<'a, T>> for Vector<T, A, G> {
     fn len(&self) -> usize {
         self.len()
     }
 
-    fn iter(&mut self) -> Iter<'a, T> {
-        self.iter()
+    unsafe fn iter(&mut self) -> RawIter<'a, T> {
+        RawIter {
+            ptr: self.ptr,
+            end: advance(self.ptr, self.len),
+            marker: PhantomData,
+        }
+    }
+
+    fn intro_sort<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> cmp::Ordering,
+    {
+        sort::intro_sort(self.deref_mut(), compare);
+    }
+
+    fn select_nth<F>(&mut self, n: usize, compare: F)
+    where
+        F: Fn(&T, &T) -> cmp::Ordering,
+    {
+        sort::select_nth(self.deref_mut(), n, compare);
+    }
+
+    fn partial_sort<F>(&mut self, k: usize, compare: F)
+    where
+        F: Fn(&T, &T) -> cmp::Ordering,
+    {
+        sort::partial_sort(self.deref_mut(), k, compare);
     }
 }
 
-impl<T: Ord + Copy> Ordered<T> for Vector<T> {
-    fn push(&mut self, value: &T) {
+impl<T: Ord, A: Allocator, G: Growth> Ordered<T> for Vector<T, A, G> {
+    fn push(&mut self, value: T) {
         let len = self.len();
         self.insert(len, value);
         self.insertion_sort(|a, b| a.cmp(b));
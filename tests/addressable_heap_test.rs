@@ -0,0 +1,123 @@
+extern crate rand;
+extern crate rust_data_structure;
+
+use rand::Rng;
+use rust_data_structure::heap::binomial::BinomialHeap;
+use rust_data_structure::heap::fibonacci::FibonacciHeap;
+use rust_data_structure::heap::*;
+use std::collections::HashMap;
+
+macro_rules! property_test {
+    ($name: ident, $heap: ident) => {
+        #[test]
+        fn $name() {
+            let mut rng = rand::thread_rng();
+            let mut h = $heap::new();
+            let mut oracle = Heap::new();
+            let mut live = HashMap::new();
+            let mut handles = Vec::new();
+
+            for id in 0..5000usize {
+                match rng.gen_range(0, 8) {
+                    0..=2 => {
+                        let value = (rng.gen_range(0, 1000), id);
+                        let handle = h.push(value);
+                        assert_eq!(unsafe { *h.get(handle) }, value);
+                        handles.push((handle, id));
+                        live.insert(id, value);
+                        oracle.insert(value);
+                    }
+                    3 => {
+                        let mut other = $heap::new();
+                        for i in 0..rng.gen_range(0, 4) {
+                            let value = (rng.gen_range(0, 1000), id + i * 10000);
+                            handles.push((other.push(value), id + i * 10000));
+                            live.insert(id + i * 10000, value);
+                            oracle.insert(value);
+                        }
+                        h.meld(other);
+                    }
+                    4 | 5 if !live.is_empty() => {
+                        while live.get(&oracle.max().1) != Some(oracle.max()) {
+                            oracle.del_max();
+                        }
+                        let value = h.del_max();
+                        assert_eq!(value, oracle.del_max());
+                        live.remove(&value.1);
+                    }
+                    6 if !handles.is_empty() => {
+                        let (handle, key) = handles[rng.gen_range(0, handles.len())];
+                        if let Some(&value) = live.get(&key) {
+                            let value = (value.0 + rng.gen_range(0, 500), key);
+                            unsafe { h.increase_priority(handle, value) };
+                            live.insert(key, value);
+                            oracle.insert(value);
+                        }
+                    }
+                    7 if !handles.is_empty() => {
+                        let (handle, key) = handles.swap_remove(rng.gen_range(0, handles.len()));
+                        if let Some(value) = live.remove(&key) {
+                            assert_eq!(unsafe { h.delete(handle) }, value);
+                        }
+                    }
+                    _ => (),
+                }
+
+                assert_eq!(h.size(), live.len());
+                if !live.is_empty() {
+                    while live.get(&oracle.max().1) != Some(oracle.max()) {
+                        oracle.del_max();
+                    }
+                    assert_eq!(h.max(), oracle.max());
+                }
+            }
+
+            while !h.empty() {
+                h.del_max();
+            }
+        }
+    };
+}
+
+property_test!(test_binomial_heap, BinomialHeap);
+property_test!(test_fibonacci_heap, FibonacciHeap);
+
+#[test]
+fn test_increase_priority() {
+    let mut b = BinomialHeap::new();
+    let mut f = FibonacciHeap::new();
+    let handles: Vec<_> = (0..10).map(|i| (b.push(i * 10), f.push(i * 10))).collect();
+
+    unsafe {
+        b.increase_priority(handles[2].0, 95);
+        f.increase_priority(handles[2].1, 95);
+    }
+    assert_eq!(b.del_max(), 95);
+    assert_eq!(f.del_max(), 95);
+
+    let mut b = BinomialHeap::with_comparator(MinComparator);
+    let mut f = FibonacciHeap::with_comparator(MinComparator);
+    let handles: Vec<_> = (0..10).map(|i| (b.push(i * 10), f.push(i * 10))).collect();
+    b.del_max();
+    f.del_max();
+
+    unsafe {
+        b.increase_priority(handles[7].0, 5);
+        f.increase_priority(handles[7].1, 5);
+        assert_eq!(b.del_max(), 5);
+        assert_eq!(f.del_max(), 5);
+        assert_eq!(b.delete(handles[9].0), 90);
+        assert_eq!(f.delete(handles[9].1), 90);
+    }
+    assert_eq!(b.size(), 7);
+    assert_eq!(f.size(), 7);
+}
+
+#[test]
+#[should_panic(expected = "new key ranks below the current key")]
+fn test_increase_priority_panic() {
+    let mut h = FibonacciHeap::new();
+    let handle = h.push(3);
+
+    unsafe { h.increase_priority(handle, 1) };
+}
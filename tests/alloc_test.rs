@@ -0,0 +1,132 @@
+extern crate rust_data_structure;
+
+use rust_data_structure::bin_tree::{BinNode, BinTree};
+use rust_data_structure::heap::leftist::LeftistHeap;
+use rust_data_structure::heap::{MaxComparator, PriorityQueue};
+use rust_data_structure::list::List;
+use rust_data_structure::utility::{AllocErr, Allocator, Global, IndexErr};
+use rust_data_structure::vector::Vector;
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr;
+
+#[derive(Default)]
+struct Counter {
+    allocs: Cell<usize>,
+    frees: Cell<usize>,
+}
+
+impl Allocator for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocs.set(self.allocs.get() + 1);
+        Global.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.frees.set(self.frees.get() + 1);
+        Global.dealloc(ptr, layout)
+    }
+}
+
+struct Exhausted;
+
+impl Allocator for Exhausted {
+    unsafe fn alloc(&self, _: Layout) -> *mut u8 {
+        ptr::null_mut()
+    }
+
+    unsafe fn dealloc(&self, _: *mut u8, _: Layout) {}
+}
+
+#[derive(PartialEq)]
+struct Arena(u8);
+
+impl Allocator for Arena {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Global.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        Global.dealloc(ptr, layout)
+    }
+}
+
+#[test]
+fn test_vector_alloc() {
+    let counter = Counter::default();
+    {
+        let mut v = Vector::new_in(&counter);
+        for i in 0..100 {
+            v.insert(i, i);
+        }
+        v.remove(0, 90);
+        assert_eq!(v[0], 90);
+    }
+
+    assert!(counter.allocs.get() > 1);
+    assert_eq!(counter.allocs.get(), counter.frees.get());
+}
+
+#[test]
+fn test_list_alloc() {
+    let counter = Counter::default();
+    {
+        let mut l = List::new_in(&counter);
+        for i in 0..10 {
+            l.insert(i, i);
+        }
+        l.remove(2, 5);
+        assert_eq!(counter.allocs.get(), 12);
+    }
+
+    assert_eq!(counter.allocs.get(), counter.frees.get());
+}
+
+#[test]
+fn test_bin_tree_alloc() {
+    let counter = Counter::default();
+    {
+        let mut t = BinTree::<char, BinNode<char>, _>::new_in(&counter);
+        let root = t.insert_as_root('a').unwrap();
+        t.insert_as_lc(root, 'b').unwrap();
+        t.insert_as_rc(root, 'c').unwrap();
+        assert!(t.insert_as_rc(root, 'd').is_err());
+        assert_eq!(t.size(), 3);
+    }
+
+    assert_eq!(counter.allocs.get(), 4);
+    assert_eq!(counter.allocs.get(), counter.frees.get());
+}
+
+#[test]
+fn test_alloc_failure() {
+    let mut v = Vector::from_slice_in(&[], Exhausted);
+
+    assert_eq!(
+        v.try_insert(0, 1),
+        Err(IndexErr::Alloc(AllocErr::OutOfMemory))
+    );
+    assert!(v.empty());
+}
+
+#[test]
+#[should_panic(expected = "allocators differ")]
+fn test_splice_foreign_alloc() {
+    let mut a = List::new_in(Arena(0));
+    let mut b = List::new_in(Arena(1));
+    a.insert(0, 1);
+    b.insert(0, 2);
+
+    a.cursor_front_mut().splice_before(b);
+}
+
+#[test]
+#[should_panic(expected = "allocators differ")]
+fn test_meld_foreign_alloc() {
+    let mut a = LeftistHeap::with_comparator_in(MaxComparator, Arena(0));
+    let mut b = LeftistHeap::with_comparator_in(MaxComparator, Arena(1));
+    a.insert(1);
+    b.insert(2);
+
+    a.meld(b);
+}
@@ -7,7 +7,7 @@ fn test_bst() {
     let mut arr = [1234, 3, 13, 23, 6, 30, 54, 531, 213, 40, 10];
 
     for n in arr.iter() {
-        bst.insert(n);
+        bst.insert(*n);
     }
 
     for n in &mut arr {
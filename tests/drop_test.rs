@@ -0,0 +1,177 @@
+extern crate rust_data_structure;
+
+use rust_data_structure::bin_tree::search::{AVLTree, RedBlackTree, Search, BST};
+use rust_data_structure::bin_tree::RawBinTree;
+use rust_data_structure::list::List;
+use rust_data_structure::queue::Queue;
+use rust_data_structure::stack::Stack;
+use rust_data_structure::vector::Vector;
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+struct Counted {
+    key: i32,
+    drops: Rc<Cell<usize>>,
+}
+
+impl Counted {
+    fn new(value: i32, counter: &Rc<Cell<usize>>) -> Self {
+        Counted {
+            key: value,
+            drops: counter.clone(),
+        }
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+impl PartialEq for Counted {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Counted {}
+
+impl PartialOrd for Counted {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Counted {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+#[test]
+fn test_vector_drop() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut v = Vector::new();
+        for i in 0..20 {
+            v.insert(0, Counted::new(i, &drops));
+        }
+        assert_eq!(drops.get(), 0);
+
+        v.remove(3, 8);
+        assert_eq!(drops.get(), 5);
+        assert_eq!(v[3].key, 11);
+
+        let top = v.pop();
+        assert_eq!(top.key, 0);
+        assert_eq!(drops.get(), 5);
+        drop(top);
+        assert_eq!(drops.get(), 6);
+
+        v.clear();
+        assert_eq!(drops.get(), 20);
+
+        v.push(Counted::new(0, &drops));
+        v.push(Counted::new(1, &drops));
+    }
+    assert_eq!(drops.get(), 22);
+}
+
+#[test]
+fn test_vector_into_iter_drop() {
+    let drops = Rc::new(Cell::new(0));
+    let mut v = Vector::new();
+    for i in 0..10 {
+        v.push(Counted::new(i, &drops));
+    }
+
+    let mut it = v.into_iter();
+    assert_eq!(it.next().unwrap().key, 0);
+    assert_eq!(drops.get(), 1);
+    drop(it);
+    assert_eq!(drops.get(), 10);
+}
+
+#[test]
+fn test_vector_string() {
+    let mut v = Vector::new();
+    for i in 0..100 {
+        v.push(i.to_string());
+    }
+    v.remove(10, 90);
+
+    assert_eq!(v.len(), 20);
+    assert_eq!(v[10], "90");
+}
+
+#[test]
+fn test_list_drop() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut l = List::<Counted>::new();
+        for i in 0..10 {
+            l.insert(0, Counted::new(i % 5, &drops));
+        }
+
+        l.remove(0, 2);
+        assert_eq!(drops.get(), 2);
+
+        l.deduplicate();
+        assert_eq!(drops.get(), 5);
+        assert_eq!(l.len(), 5);
+
+        let front = l.dequeue();
+        assert_eq!(front.key, 2);
+        drop(front);
+        assert_eq!(drops.get(), 6);
+
+        l.clear();
+        assert_eq!(drops.get(), 10);
+
+        l.enqueue(Counted::new(1, &drops));
+    }
+    assert_eq!(drops.get(), 11);
+}
+
+#[test]
+fn test_bin_tree_drop() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut t = RawBinTree::new();
+        let root = t.insert_as_root(Counted::new(0, &drops)).unwrap();
+        let lc = t.insert_as_lc(root, Counted::new(1, &drops)).unwrap();
+        t.insert_as_rc(root, Counted::new(2, &drops)).unwrap();
+        t.insert_as_lc(lc, Counted::new(3, &drops)).unwrap();
+
+        t.remove(lc);
+        assert_eq!(drops.get(), 2);
+        assert_eq!(t.size(), 2);
+    }
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn test_search_tree_drop() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut bst = BST::new();
+        let mut avl = AVLTree::new();
+        let mut rb = RedBlackTree::new();
+        for i in 0..50 {
+            let key = (i * 17) % 50;
+            bst.insert(Counted::new(key, &drops));
+            avl.insert(Counted::new(key, &drops));
+            rb.insert(Counted::new(key, &drops));
+        }
+
+        for i in 0..10 {
+            bst.remove(&Counted::new(i, &drops));
+            avl.remove(&Counted::new(i, &drops));
+            rb.remove(&Counted::new(i, &drops));
+        }
+        assert_eq!(drops.get(), 60);
+    }
+    assert_eq!(drops.get(), 180);
+}
@@ -0,0 +1,150 @@
+extern crate rand;
+extern crate rust_data_structure;
+
+use rust_data_structure::sort::external::{BinaryCodec, Codec, ExternalSort, LineCodec};
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("external-sort-test-{}", name));
+
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+struct PairCodec;
+
+impl Codec<(u32, u32)> for PairCodec {
+    fn encode<W: Write>(&self, value: &(u32, u32), writer: &mut W) -> io::Result<()> {
+        BinaryCodec.encode(&value.0, writer)?;
+        BinaryCodec.encode(&value.1, writer)
+    }
+
+    fn decode<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<(u32, u32)>> {
+        let a = match BinaryCodec.decode(reader)? {
+            Some(a) => a,
+            None => return Ok(None),
+        };
+        match BinaryCodec.decode(reader)? {
+            Some(b) => Ok(Some((a, b))),
+            None => Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+        }
+    }
+}
+
+#[test]
+fn test_sort_iter() {
+    let dir = temp_dir("iter");
+    let data: Vec<u64> = (0..100000).map(|_| rand::random()).collect();
+    let mut sorted = data.clone();
+    sorted.sort();
+
+    let merged = ExternalSort::new(BinaryCodec, |a: &u64, b: &u64| a.cmp(b))
+        .chunk_size(1000)
+        .temp_dir(&dir)
+        .sort(data)
+        .unwrap();
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 100);
+
+    let result: Vec<u64> = merged.map(|x| x.unwrap()).collect();
+    assert_eq!(result, sorted);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+    let empty = ExternalSort::new(BinaryCodec, |a: &u64, b: &u64| a.cmp(b))
+        .sort(Vec::new())
+        .unwrap();
+    assert_eq!(empty.count(), 0);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_sort_stable() {
+    let dir = temp_dir("stable");
+    let data: Vec<(u32, u32)> = (0..20000)
+        .map(|i| (rand::random::<u32>() % 50, i))
+        .collect();
+    let mut sorted = data.clone();
+    sorted.sort_by_key(|p| p.0);
+
+    let merged = ExternalSort::new(PairCodec, |a: &(u32, u32), b: &(u32, u32)| a.0.cmp(&b.0))
+        .chunk_size(777)
+        .temp_dir(&dir)
+        .sort(data)
+        .unwrap();
+
+    let result: Vec<(u32, u32)> = merged.map(|x| x.unwrap()).collect();
+    assert_eq!(result, sorted);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_sort_fan_in() {
+    let dir = temp_dir("fan-in");
+    let data: Vec<(u32, u32)> = (0..10050)
+        .map(|i| (rand::random::<u32>() % 50, i))
+        .collect();
+    let mut sorted = data.clone();
+    sorted.sort_by_key(|p| p.0);
+
+    let merged = ExternalSort::new(PairCodec, |a: &(u32, u32), b: &(u32, u32)| a.0.cmp(&b.0))
+        .chunk_size(100)
+        .fan_in(4)
+        .temp_dir(&dir)
+        .sort(data)
+        .unwrap();
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+    let result: Vec<(u32, u32)> = merged.map(|x| x.unwrap()).collect();
+    assert_eq!(result, sorted);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_sort_file() {
+    let dir = temp_dir("file");
+    let input = dir.join("input.txt");
+    let output = dir.join("output.txt");
+
+    let lines: Vec<String> = (0..5000)
+        .map(|_| format!("{:x}", rand::random::<u32>()))
+        .collect();
+    fs::write(&input, lines.join("\n")).unwrap();
+
+    ExternalSort::new(LineCodec, |a: &String, b: &String| a.cmp(b))
+        .chunk_size(256)
+        .temp_dir(&dir)
+        .sort_file(&input, &output)
+        .unwrap();
+
+    let mut sorted = lines.clone();
+    sorted.sort();
+    let result = fs::read_to_string(&output).unwrap();
+    assert_eq!(result.lines().collect::<Vec<&str>>(), sorted);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_sort_truncated_file() {
+    let dir = temp_dir("truncated");
+    let input = dir.join("input.bin");
+    let output = dir.join("output.bin");
+
+    fs::write(&input, [1u8, 0, 0, 0, 2, 0]).unwrap();
+
+    let result = ExternalSort::new(BinaryCodec, |a: &u32, b: &u32| a.cmp(b))
+        .temp_dir(&dir)
+        .sort_file(&input, &output);
+    assert_eq!(result.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
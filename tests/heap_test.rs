@@ -1,5 +1,6 @@
 extern crate rust_data_structure;
 use rust_data_structure::heap::*;
+use rust_data_structure::vector::Vector;
 use std::cmp::Reverse;
 
 #[test]
@@ -8,7 +9,7 @@ fn test_heap() {
     let mut arr = [1234, 3, 6, 0, 54, 531, 213];
 
     for n in arr.iter() {
-        h.insert(&Reverse(*n));
+        h.insert(Reverse(*n));
     }
     assert_eq!(arr.len(), h.size());
 
@@ -17,3 +18,147 @@ fn test_heap() {
         assert_eq!(*n, h.del_max().0);
     }
 }
+
+#[test]
+fn test_no_default() {
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+    struct Job(u32);
+
+    let mut h = Heap::new();
+
+    h.insert(Job(3));
+    h.insert(Job(7));
+    h.insert(Job(5));
+    assert_eq!(*h.max(), Job(7));
+    assert_eq!(h.del_max(), Job(7));
+    assert_eq!(h.del_max(), Job(5));
+    assert_eq!(h.del_max(), Job(3));
+    assert_eq!(h.size(), 0);
+}
+
+#[test]
+fn test_from_vector() {
+    let arr = [1234, 3, 6, 0, 54, 531, 213, 6];
+    let mut h = Heap::from_vector(Vector::from_slice(&arr));
+    let mut sorted = arr;
+
+    sorted.sort();
+    assert_eq!(*h.max(), 1234);
+    assert_eq!(h.del_max(), 1234);
+    h.insert(7);
+    assert_eq!(
+        h.into_sorted_vector(),
+        Vector::from_slice(&[0, 3, 6, 6, 7, 54, 213, 531])
+    );
+
+    let h = Heap::from_vector(Vector::from_slice(&arr));
+    assert_eq!(h.into_sorted_vector(), Vector::from_slice(&sorted));
+}
+
+#[test]
+fn test_peek_mut() {
+    let mut h = Heap::from_vector(Vector::from_slice(&[5, 9, 2, 7]));
+
+    {
+        let mut top = h.peek_mut().unwrap();
+        assert_eq!(*top, 9);
+        *top = 1;
+    }
+    assert_eq!(*h.max(), 7);
+    assert_eq!(PeekMut::pop(h.peek_mut().unwrap()), 7);
+    assert_eq!(h.size(), 3);
+    assert_eq!(*h.max(), 5);
+
+    let mut e = Heap::<i32>::new();
+    assert!(e.peek_mut().is_none());
+}
+
+#[test]
+fn test_append() {
+    let mut a = Heap::from_vector(Vector::from_slice(&[1, 8, 3]));
+    let mut b = Heap::from_vector(Vector::from_slice(&[4, 9, 2, 7, 5]));
+
+    a.append(&mut b);
+    assert_eq!(a.size(), 8);
+    assert_eq!(b.size(), 0);
+
+    let c = Heap::from_vector(Vector::from_slice(&[6]));
+    let h = a.merge(c);
+    assert_eq!(
+        h.into_sorted_vector(),
+        Vector::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9])
+    );
+}
+
+#[test]
+fn test_min_heap() {
+    let mut h = MinHeap::default();
+    let mut arr = [1234, 3, 6, 0, 54, 531, 213];
+
+    for n in arr.iter() {
+        h.insert(*n);
+    }
+
+    arr.sort();
+    for n in arr.iter() {
+        assert_eq!(*n, h.del_max());
+    }
+
+    let h = MinHeap::from_vector_with(Vector::from_slice(&[4, 1, 3]), MinComparator);
+    assert_eq!(h.into_sorted_vector(), Vector::from_slice(&[4, 3, 1]));
+
+    let mut h: MinHeap<i32> = MinHeap::new_min();
+    h.insert(2);
+    h.insert(1);
+    assert_eq!(h.del_max(), 1);
+}
+
+#[test]
+fn test_comparator() {
+    struct Job {
+        name: &'static str,
+        deadline: u32,
+    }
+
+    let mut h = Heap::by_key(|job: &Job| Reverse(job.deadline));
+
+    h.insert(Job {
+        name: "b",
+        deadline: 20,
+    });
+    h.insert(Job {
+        name: "a",
+        deadline: 10,
+    });
+    h.insert(Job {
+        name: "c",
+        deadline: 30,
+    });
+    assert_eq!(h.del_max().name, "a");
+    assert_eq!(h.del_max().name, "b");
+
+    let mut h = Heap::with_comparator(|a: &i32, b: &i32| (a % 10).cmp(&(b % 10)));
+
+    h.insert(19);
+    h.insert(25);
+    h.insert(31);
+    assert_eq!(*h.max(), 19);
+}
+
+#[test]
+#[should_panic(expected = "this heap is empty")]
+fn test_empty_del_max() {
+    let mut h: Heap<i32> = Heap::new();
+
+    h.insert(1);
+    h.del_max();
+    h.del_max();
+}
+
+#[test]
+#[should_panic(expected = "this heap is empty")]
+fn test_empty_max() {
+    let h: Heap<i32> = Heap::new();
+
+    h.max();
+}
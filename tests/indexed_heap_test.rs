@@ -0,0 +1,66 @@
+extern crate rust_data_structure;
+
+use rust_data_structure::heap::indexed::IndexedHeap;
+use rust_data_structure::heap::*;
+
+#[test]
+fn test_indexed_heap() {
+    let mut h = IndexedHeap::new();
+
+    assert_eq!(h.push("a", 3), None);
+    assert_eq!(h.push("b", 7), None);
+    assert_eq!(h.push("c", 5), None);
+    assert_eq!(h.push("d", 1), None);
+    assert!(h.contains(&"c"));
+    assert_eq!(h.peek(), Some((&"b", &7)));
+
+    assert_eq!(h.change_priority(&"d", 9), Some(1));
+    assert_eq!(h.peek(), Some((&"d", &9)));
+    assert_eq!(h.push("b", 2), Some(7));
+    assert_eq!(h.priority(&"b"), Some(&2));
+
+    assert_eq!(h.remove(&"c"), Some(5));
+    assert_eq!(h.remove(&"c"), None);
+    assert!(!h.contains(&"c"));
+    assert_eq!(h.change_priority(&"c", 1), None);
+
+    assert_eq!(h.pop(), Some(("d", 9)));
+    assert_eq!(h.del_max(), ("a", 3));
+    assert_eq!(h.pop(), Some(("b", 2)));
+    assert_eq!(h.pop(), None);
+    assert!(h.empty());
+}
+
+#[test]
+fn test_dijkstra() {
+    let edges: [&[(usize, u32)]; 5] = [
+        &[(1, 10), (2, 3)],
+        &[(3, 2)],
+        &[(1, 4), (3, 8), (4, 2)],
+        &[(4, 5)],
+        &[(3, 1)],
+    ];
+    let mut dist = [u32::MAX; 5];
+    let mut h: IndexedHeap<usize, u32, MinComparator> = IndexedHeap::default();
+
+    dist[0] = 0;
+    h.push(0, 0);
+    while let Some((u, d)) = h.pop() {
+        for &(v, w) in edges[u].iter() {
+            if d + w < dist[v] {
+                dist[v] = d + w;
+                h.push(v, d + w);
+            }
+        }
+    }
+
+    assert_eq!(dist, [0, 7, 3, 6, 5]);
+}
+
+#[test]
+#[should_panic(expected = "this heap is empty")]
+fn test_empty_max() {
+    let h: IndexedHeap<&str, i32> = IndexedHeap::new();
+
+    h.max();
+}
@@ -2,6 +2,8 @@
 extern crate rust_data_structure;
 
 use rust_data_structure::list::List;
+use rust_data_structure::queue::Queue;
+use rust_data_structure::utility::IndexErr;
 
 #[test]
 fn test_base() {
@@ -15,9 +17,9 @@ fn test_base() {
 fn test_insert_remove() {
     let mut l = List::<i32>::new();
 
-    l.insert(0, &1);
+    l.insert(0, 1);
     assert_eq!(l[0], 1);
-    l.insert(1, &7);
+    l.insert(1, 7);
     assert_eq!(l[1], 7);
     l.remove(0, 2);
     assert!(l.empty());
@@ -104,3 +106,149 @@ fn test_sort() {
         assert_eq!(a, b);
     }
 }
+
+#[test]
+fn test_clone() {
+    let mut l = List::new();
+    for i in 0..10 {
+        l.insert(i, i.to_string());
+    }
+
+    let mut o = l.clone();
+    o[3].push('!');
+    assert_eq!(l[3], "3");
+    assert_eq!(o[3], "3!");
+
+    let mut s = list![String::from("x"), String::from("y")];
+    s.clone_from(&l);
+    assert_eq!(s.len(), 10);
+    for i in 0..10 {
+        assert_eq!(s[i], l[i]);
+    }
+
+    s.clone_from(&list![String::from("z")]);
+    assert_eq!(s.len(), 1);
+    assert_eq!(s[0], "z");
+}
+
+#[test]
+fn test_try() {
+    let mut l = list![1, 2, 3];
+
+    assert_eq!(l.get(2), Some(&3));
+    assert_eq!(l.get(3), None);
+    *l.get_mut(0).unwrap() = 7;
+    assert_eq!(l[0], 7);
+
+    assert_eq!(
+        l.try_insert(4, 0),
+        Err(IndexErr::OutOfBounds { index: 4, len: 3 })
+    );
+    assert_eq!(l.try_insert(3, 4), Ok(()));
+    assert_eq!(
+        l.try_remove(2, 5),
+        Err(IndexErr::OutOfBounds { index: 5, len: 4 })
+    );
+    assert_eq!(
+        l.try_remove(3, 2),
+        Err(IndexErr::InvalidRange { lo: 3, hi: 2 })
+    );
+    assert_eq!(l.try_remove(1, 3), Ok(()));
+    assert_eq!(l.len(), 2);
+    assert_eq!(l[1], 4);
+}
+
+#[test]
+fn test_cursor() {
+    let mut l = list![1, 2, 3];
+
+    {
+        let mut c = l.cursor_front_mut();
+        assert_eq!(c.index(), Some(0));
+        c.move_next();
+        assert_eq!(c.current(), Some(&mut 2));
+        c.insert_before(5);
+        c.insert_after(6);
+        assert_eq!(c.index(), Some(2));
+        assert_eq!(c.remove_current(), Some(2));
+        assert_eq!(c.current(), Some(&mut 6));
+        c.move_next();
+        c.move_next();
+        assert_eq!(c.index(), None);
+        assert_eq!(c.current(), None);
+        c.insert_after(0);
+        c.insert_before(7);
+        c.move_next();
+        assert_eq!(c.current(), Some(&mut 0));
+        c.move_prev();
+        assert_eq!(c.index(), None);
+        c.move_prev();
+        assert_eq!(c.index(), Some(5));
+        assert_eq!(c.current(), Some(&mut 7));
+    }
+    let v: Vec<i32> = l.iter().map(|x| *x).collect();
+    assert_eq!(v, vec![0, 1, 5, 6, 3, 7]);
+}
+
+#[test]
+fn test_cursor_split_splice() {
+    let mut l = list![1, 2, 3, 4];
+    let mut tail;
+
+    {
+        let mut c = l.cursor_front_mut();
+        c.move_next();
+        tail = c.split_after();
+        assert_eq!(c.index(), Some(1));
+        c.splice_before(list![7, 8]);
+        assert_eq!(c.index(), Some(3));
+        assert_eq!(c.current(), Some(&mut 2));
+    }
+    assert_eq!(l.len(), 4);
+    let v: Vec<i32> = l.iter().map(|x| *x).collect();
+    assert_eq!(v, vec![1, 7, 8, 2]);
+    let v: Vec<i32> = tail.iter().map(|x| *x).collect();
+    assert_eq!(v, vec![3, 4]);
+
+    {
+        let mut c = tail.cursor_back_mut();
+        assert_eq!(c.current(), Some(&mut 4));
+        c.move_next();
+        c.splice_before(list![5]);
+        let rest = c.split_after();
+        assert_eq!(rest.len(), 3);
+    }
+    assert!(tail.empty());
+}
+
+#[test]
+fn test_no_default() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Token(&'static str);
+
+    let mut l = list![Token("a"), Token("b")];
+    let c = l.clone();
+
+    l.enqueue(Token("c"));
+    assert_eq!(l.dequeue(), Token("a"));
+    assert_eq!(l.len(), 2);
+    assert_eq!(c[1], Token("b"));
+}
+
+#[test]
+fn test_deduplicate_no_default() {
+    let mut l = list![
+        String::from("a"),
+        String::from("b"),
+        String::from("a"),
+        String::from("c"),
+        String::from("c"),
+        String::from("c")
+    ];
+
+    l.deduplicate();
+    assert_eq!(l.len(), 3);
+    assert_eq!(l[0], "a");
+    assert_eq!(l[1], "b");
+    assert_eq!(l[2], "c");
+}
@@ -0,0 +1,47 @@
+extern crate rand;
+extern crate rust_data_structure;
+
+use rust_data_structure::heap::leftist::LeftistHeap;
+use rust_data_structure::heap::pairing::PairingHeap;
+use rust_data_structure::heap::skew::SkewHeap;
+use rust_data_structure::heap::*;
+
+macro_rules! meld_test {
+    ($name: ident, $heap: ident) => {
+        #[test]
+        fn $name() {
+            let mut values = Vec::new();
+            let mut a = $heap::new();
+            let mut b = $heap::new();
+
+            for i in 0..2000 {
+                let n = rand::random::<i32>() % 1000;
+                values.push(n);
+                if i % 2 == 0 {
+                    a.insert(n);
+                } else {
+                    b.insert(n);
+                }
+            }
+            a.meld(b);
+            assert_eq!(a.size(), values.len());
+
+            values.sort();
+            for n in values.iter().rev() {
+                assert_eq!(*n, *a.max());
+                assert_eq!(*n, a.del_max());
+            }
+            assert!(a.empty());
+
+            let mut h = $heap::with_comparator(MinComparator);
+            for i in 0..100000 {
+                h.insert(100000 - i);
+            }
+            assert_eq!(h.del_max(), 1);
+        }
+    };
+}
+
+meld_test!(test_leftist_heap, LeftistHeap);
+meld_test!(test_skew_heap, SkewHeap);
+meld_test!(test_pairing_heap, PairingHeap);
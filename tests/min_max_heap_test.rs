@@ -0,0 +1,69 @@
+extern crate rand;
+extern crate rust_data_structure;
+
+use rand::Rng;
+use rust_data_structure::heap::min_max::MinMaxHeap;
+use rust_data_structure::heap::*;
+use rust_data_structure::vector::Vector;
+
+#[test]
+fn test_min_max_heap() {
+    let mut rng = rand::thread_rng();
+    let mut h = MinMaxHeap::new();
+    let mut model = Vec::new();
+
+    for _ in 0..5000 {
+        match rng.gen_range(0, 4) {
+            0 | 1 => {
+                let n = rng.gen_range(0, 1000);
+                h.insert(n);
+                model.push(n);
+                model.sort();
+            }
+            2 if !model.is_empty() => {
+                assert_eq!(h.del_min(), model.remove(0));
+            }
+            3 if !model.is_empty() => {
+                assert_eq!(h.del_max(), model.pop().unwrap());
+            }
+            _ => (),
+        }
+
+        assert_eq!(h.size(), model.len());
+        if !model.is_empty() {
+            assert_eq!(*h.min(), model[0]);
+            assert_eq!(*h.max(), model[model.len() - 1]);
+        }
+    }
+}
+
+#[test]
+fn test_from_vector() {
+    let mut v = Vector::new();
+    let mut model = Vec::new();
+
+    for _ in 0..500 {
+        let n = rand::random::<i32>();
+        v.insert(0, n);
+        model.push(n);
+    }
+    model.sort();
+
+    let mut h = MinMaxHeap::from_vector(v);
+    for i in 0..250 {
+        assert_eq!(h.del_min(), model[i]);
+        assert_eq!(h.del_max(), model[499 - i]);
+    }
+    assert!(h.empty());
+}
+
+#[test]
+fn test_comparator() {
+    let mut h = MinMaxHeap::with_comparator(MinComparator);
+
+    for n in [5, 1, 9, 3].iter() {
+        h.insert(*n);
+    }
+    assert_eq!(*h.max(), 1);
+    assert_eq!(*h.min(), 9);
+}
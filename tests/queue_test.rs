@@ -7,9 +7,59 @@ use rust_data_structure::queue::*;
 fn test_enqueue_dequeue() {
     let mut q = List::<i32>::new();
 
-    q.enqueue(&34);
+    q.enqueue(34);
     assert_eq!(34, *q.front());
-    q.enqueue(&3456);
+    q.enqueue(3456);
     assert_eq!(2, q.len());
     assert_eq!(34, q.dequeue());
 }
+
+#[test]
+fn test_try_dequeue() {
+    let mut q = List::<i32>::new();
+
+    assert_eq!(q.try_front(), None);
+    assert_eq!(q.try_dequeue(), None);
+    q.enqueue(5);
+    assert_eq!(q.try_front(), Some(&mut 5));
+    assert_eq!(q.try_dequeue(), Some(5));
+    assert_eq!(q.try_dequeue(), None);
+}
+
+#[test]
+fn test_fifo() {
+    let mut q = List::<i32>::new();
+
+    for i in 0..5 {
+        q.enqueue(i);
+    }
+    for i in 0..5 {
+        assert_eq!(i, *q.front());
+        assert_eq!(i, q.dequeue());
+    }
+    assert!(q.empty());
+}
+
+#[test]
+fn test_deque() {
+    let mut d = List::<i32>::new();
+
+    assert_eq!(d.try_pop_front(), None);
+    assert_eq!(d.try_pop_back(), None);
+    assert_eq!(d.try_back(), None);
+
+    d.push_back(2);
+    d.push_front(1);
+    d.push_back(3);
+    d.push_front(0);
+    assert_eq!(4, d.len());
+    assert_eq!(0, *d.front());
+    assert_eq!(3, *d.back());
+    *d.back() = 4;
+
+    assert_eq!(4, d.pop_back());
+    assert_eq!(0, d.pop_front());
+    assert_eq!(2, d.pop_back());
+    assert_eq!(1, d.pop_front());
+    assert!(d.empty());
+}
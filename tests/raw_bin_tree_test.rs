@@ -8,10 +8,10 @@ fn test_base() -> Result<(), InsertErr> {
     let arr = ['a', 'b', 'c'];
 
     assert!(t.empty());
-    t.insert_as_root(&'a');
+    t.insert_as_root('a')?;
     let root = t.root().unwrap();
-    t.insert_as_lc(root, &'b')?;
-    t.insert_as_rc(root, &'c')?;
+    t.insert_as_lc(root, 'b')?;
+    t.insert_as_rc(root, 'c')?;
 
     assert_eq!(t.iter().count(), 3);
     assert_eq!(t.size(), 3);
@@ -27,10 +27,10 @@ fn test_base() -> Result<(), InsertErr> {
 fn test_attach() -> Result<(), InsertErr> {
     let mut t = RawBinTree::<char>::new();
     let arr = ['a', 'c', 'b'];
-    t.insert_as_root(&'a');
+    t.insert_as_root('a')?;
     let root = t.root().unwrap();
-    let l = t.insert_as_lc(root, &'b').unwrap();
-    let r = t.insert_as_rc(root, &'c').unwrap();
+    let l = t.insert_as_lc(root, 'b').unwrap();
+    let r = t.insert_as_rc(root, 'c').unwrap();
     let l = t.secede(l);
     let r = t.secede(r);
     t.attach_as_lc(root, r)?;
@@ -45,3 +45,31 @@ fn test_attach() -> Result<(), InsertErr> {
 
     Ok(())
 }
+
+#[test]
+fn test_clone() -> Result<(), InsertErr> {
+    let mut t = RawBinTree::<String>::new();
+    let root = t.insert_as_root(String::from("a"))?;
+    let l = t.insert_as_lc(root, String::from("b"))?;
+    t.insert_as_rc(l, String::from("c"))?;
+    t.insert_as_rc(root, String::from("d"))?;
+
+    let mut o = t.clone();
+    assert_eq!(o.size(), 4);
+
+    let root = o.root().unwrap();
+    assert!(o.insert_as_lc(root, String::new()).is_err());
+    assert!(o.insert_as_rc(root, String::new()).is_err());
+
+    for n in o.iter() {
+        n.push('!');
+    }
+    for (a, b) in t.iter().zip(["a", "b", "c", "d"].iter()) {
+        assert_eq!(a, b);
+    }
+    for (a, b) in o.iter().zip(["a!", "b!", "c!", "d!"].iter()) {
+        assert_eq!(a, b);
+    }
+
+    Ok(())
+}
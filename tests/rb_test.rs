@@ -0,0 +1,88 @@
+extern crate rand;
+extern crate rust_data_structure;
+use rand::Rng;
+use rust_data_structure::bin_tree::search::{RedBlackTree, Search, BST};
+use std::collections::BTreeSet;
+
+#[test]
+fn test_rb() {
+    let mut rb = RedBlackTree::<i32>::new();
+    let mut arr = [1234, 3, 13, 23, 6, 30, 54, 531, 213, 40, 10];
+
+    for n in arr.iter() {
+        rb.insert(*n);
+    }
+
+    for n in &mut arr {
+        assert_eq!(rb.search(*n, |a, b| a.cmp(b)), Some(n));
+    }
+
+    assert_eq!(rb.search(777, |a, b| a.cmp(b)), None);
+    rb.remove(&23);
+    rb.remove(&2);
+    assert_eq!(10, rb.size());
+    assert_eq!(rb.search(23, |a, b| a.cmp(b)), None);
+    assert_eq!(rb.search(6, |a, b| a.cmp(b)), Some(&mut 6));
+}
+
+#[test]
+fn test_rb_remove_all() {
+    let mut rb = RedBlackTree::<i32>::new();
+
+    for n in 0..1000 {
+        rb.insert((n * 7919) % 1000);
+    }
+    assert_eq!(1000, rb.size());
+    assert_eq!(1000, rb.iter().count());
+
+    for n in (0..1000).filter(|n| n % 3 != 0) {
+        rb.remove(&n);
+        assert_eq!(rb.search(n, |a, b| a.cmp(b)), None);
+    }
+    assert_eq!(334, rb.size());
+    assert_eq!(334, rb.iter().count());
+
+    for n in (0..1000).filter(|n| n % 3 == 0) {
+        assert_eq!(rb.search(n, |a, b| a.cmp(b)), Some(&mut n.clone()));
+        rb.remove(&n);
+    }
+    assert!(rb.iter().next().is_none());
+    assert_eq!(0, rb.size());
+}
+
+#[test]
+fn test_rb_insert_root() {
+    let mut rb = RedBlackTree::<i32>::new();
+    let mut bst = BST::<i32>::new();
+
+    assert_eq!(rb.insert(1).is_none(), bst.insert(1).is_none());
+    assert_eq!(rb.insert(2).is_none(), bst.insert(2).is_none());
+    assert_eq!(rb.insert(2).is_none(), bst.insert(2).is_none());
+}
+
+#[test]
+fn test_rb_random() {
+    let mut rng = rand::thread_rng();
+    let mut rb = RedBlackTree::<i32>::new();
+    let mut oracle = BTreeSet::new();
+
+    for _ in 0..5000 {
+        let n = rng.gen_range(0, 500);
+
+        if rng.gen_range(0, 3) == 0 {
+            rb.remove(&n);
+            oracle.remove(&n);
+        } else {
+            rb.insert(n);
+            oracle.insert(n);
+        }
+
+        assert!(rb.is_red_black());
+        assert_eq!(rb.size(), oracle.len());
+    }
+
+    let mut values: Vec<i32> = rb.iter().map(|n| *n).collect();
+    values.sort();
+    let expected: Vec<i32> = oracle.into_iter().collect();
+    assert_eq!(values, expected);
+}
@@ -0,0 +1,109 @@
+extern crate rust_data_structure;
+
+use rust_data_structure::queue::*;
+use rust_data_structure::ring_buffer::*;
+use rust_data_structure::stack::Stack;
+
+#[test]
+fn test_queue() {
+    let mut q = RingBuffer::with_capacity(2);
+
+    for i in 0..10 {
+        q.enqueue(i);
+    }
+    assert_eq!(q.len(), 10);
+    for i in 0..10 {
+        assert_eq!(i, *q.front());
+        assert_eq!(i, q.dequeue());
+    }
+    assert_eq!(q.try_dequeue(), None);
+}
+
+#[test]
+fn test_stack() {
+    let mut s = RingBuffer::new();
+
+    for i in 0..10 {
+        s.push(i);
+    }
+    for i in (0..10).rev() {
+        assert_eq!(i, *s.top());
+        assert_eq!(i, s.pop());
+    }
+    assert_eq!(s.try_pop(), None);
+}
+
+#[test]
+fn test_deque() {
+    let mut d = RingBuffer::with_capacity(4);
+
+    d.push_back(2);
+    d.push_back(3);
+    d.push_front(1);
+    d.push_front(0);
+    assert!(d.full());
+    d.push_back(4);
+    assert_eq!(d.capacity(), 8);
+
+    let v: Vec<i32> = d.iter().cloned().collect();
+    assert_eq!(v, vec![0, 1, 2, 3, 4]);
+    assert_eq!(4, d.pop_back());
+    assert_eq!(0, d.pop_front());
+    assert_eq!(3, *d.back());
+    assert_eq!(1, d[0]);
+    d[0] = 5;
+    assert_eq!(d.get(0), Some(&5));
+    assert_eq!(d.get(3), None);
+}
+
+#[test]
+fn test_slices() {
+    let mut d = RingBuffer::with_capacity(4);
+
+    d.push_back(2);
+    d.push_back(3);
+    d.push_front(1);
+    assert_eq!(d.as_slices(), (&[1][..], &[2, 3][..]));
+
+    d.rotate_left(1);
+    let v: Vec<i32> = d.iter().cloned().collect();
+    assert_eq!(v, vec![2, 3, 1]);
+    d.rotate_right(2);
+    let v: Vec<i32> = d.iter().rev().cloned().collect();
+    assert_eq!(v, vec![2, 1, 3]);
+
+    d.as_mut_slices().0[0] = 7;
+    assert_eq!(d[0], 7);
+}
+
+#[test]
+fn test_bounded() {
+    let mut r = RingBuffer::with_overflow(3, Overflow::Reject);
+
+    for i in 0..3 {
+        assert_eq!(r.try_push_back(i), Ok(()));
+    }
+    assert_eq!(r.try_push_back(3), Err(3));
+    assert_eq!(r.try_push_front(3), Err(3));
+    assert_eq!(r.capacity(), 3);
+
+    let mut o = RingBuffer::with_overflow(3, Overflow::Overwrite);
+
+    for i in 0..5 {
+        o.enqueue(i);
+    }
+    let v: Vec<i32> = o.iter().cloned().collect();
+    assert_eq!(v, vec![2, 3, 4]);
+    o.push_front(1);
+    let v: Vec<i32> = o.iter().cloned().collect();
+    assert_eq!(v, vec![1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_reject_panic() {
+    let mut r = RingBuffer::with_overflow(1, Overflow::Reject);
+
+    r.enqueue(0);
+    r.enqueue(1);
+}
@@ -1,7 +1,12 @@
+extern crate rand;
 extern crate rust_data_structure;
 
 use rust_data_structure::list::List;
-use rust_data_structure::search::{binary_search, Ordered};
+use rust_data_structure::search::{
+    binary_search, binary_search_by, equal_range, exponential_search, interpolation_search,
+    lower_bound, partition_point, upper_bound, Ordered,
+};
+use rust_data_structure::vector::Vector;
 
 #[test]
 fn test_binary_search() {
@@ -9,7 +14,7 @@ fn test_binary_search() {
     let mut l = List::new();
 
     for n in arr.iter() {
-        l.push(n);
+        l.push(*n);
     }
 
     for n in &mut arr {
@@ -18,3 +23,83 @@ fn test_binary_search() {
 
     assert_eq!(binary_search(&mut l, 777, |a, b| a.cmp(b), 0, 11), None);
 }
+
+fn make_sorted(size: usize) -> Vec<i32> {
+    let mut a: Vec<i32> = (0..size).map(|_| rand::random::<i32>() % 100).collect();
+    a.sort();
+    a
+}
+
+#[test]
+fn test_bounds() {
+    let a = make_sorted(1000);
+    let v = Vector::from_slice(&a);
+    let mut l = List::new();
+    for n in a.iter() {
+        let len = l.len();
+        l.insert(len, *n);
+    }
+
+    for key in -101..101 {
+        let lower = a.partition_point(|x| *x < key);
+        let upper = a.partition_point(|x| *x <= key);
+        let cmp = |k: &i32, x: &i32| k.cmp(x);
+
+        assert_eq!(lower_bound(&a[..], &key, cmp, 0, 1000), lower);
+        assert_eq!(lower_bound(&v, &key, cmp, 0, 1000), lower);
+        assert_eq!(upper_bound(&a[..], &key, cmp, 0, 1000), upper);
+        assert_eq!(upper_bound(&v, &key, cmp, 0, 1000), upper);
+        assert_eq!(equal_range(&a[..], &key, cmp, 0, 1000), lower..upper);
+        assert_eq!(partition_point(&v, |x| *x < key, 0, 1000), lower);
+
+        let expected = if lower < upper { Ok(lower) } else { Err(lower) };
+        assert_eq!(binary_search_by(&a[..], &key, cmp, 0, 1000), expected);
+        assert_eq!(exponential_search(&v, &key, cmp, 0, 1000), expected);
+        assert_eq!(
+            interpolation_search(&a[..], key as f64, |x| *x as f64, 0, 1000),
+            expected
+        );
+    }
+
+    for key in [-100, 0, 50, 99].iter() {
+        let range = equal_range(&a[..], key, |k, x| k.cmp(x), 0, 1000);
+        assert_eq!(equal_range(&l, key, |k, x| k.cmp(x), 0, 1000), range);
+    }
+}
+
+#[test]
+fn test_bounds_subrange() {
+    let a = [1, 2, 2, 2, 3, 5, 8, 8, 13];
+    let cmp = |k: &i32, x: &i32| k.cmp(x);
+
+    assert_eq!(lower_bound(&a[..], &2, cmp, 2, 9), 2);
+    assert_eq!(upper_bound(&a[..], &8, cmp, 0, 7), 7);
+    assert_eq!(binary_search_by(&a[..], &13, cmp, 0, 8), Err(8));
+    assert_eq!(exponential_search(&a[..], &5, cmp, 3, 9), Ok(5));
+    assert_eq!(exponential_search(&a[..], &0, cmp, 0, 0), Err(0));
+    assert_eq!(
+        interpolation_search(&a[..], 4.0, |x| *x as f64, 0, 9),
+        Err(5)
+    );
+    assert_eq!(
+        interpolation_search(&a[..], 14.0, |x| *x as f64, 0, 9),
+        Err(9)
+    );
+}
+
+#[test]
+fn test_skewed_search() {
+    let a: Vec<u64> = (0..64).map(|i| 1u64 << i).collect();
+
+    for (i, x) in a.iter().enumerate() {
+        assert_eq!(
+            interpolation_search(&a[..], *x as f64, |x| *x as f64, 0, 64),
+            Ok(i)
+        );
+        assert_eq!(exponential_search(&a[..], x, |k, x| k.cmp(x), 0, 64), Ok(i));
+    }
+    assert_eq!(
+        interpolation_search(&a[..], 3.0, |x| *x as f64, 0, 64),
+        Err(2)
+    );
+}
@@ -2,14 +2,63 @@ extern crate rand;
 extern crate rust_data_structure;
 
 //use rust_data_structure::sort::Sort;
+use rust_data_structure::list::List;
+use rust_data_structure::sort::linear::LinearSort;
+use rust_data_structure::sort::parallel::{ParallelSort, Parallelism};
+use rust_data_structure::stack::Stack;
 use rust_data_structure::vector::{Sort, Vector};
+use std::cell::Cell;
+use std::panic;
+use std::rc::Rc;
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::Arc;
+
+struct Counted {
+    key: i32,
+    drops: Rc<Cell<usize>>,
+}
+
+impl Counted {
+    fn new(value: i32, counter: &Rc<Cell<usize>>) -> Self {
+        Counted {
+            key: value,
+            drops: counter.clone(),
+        }
+    }
+}
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+struct Shared {
+    key: i32,
+    drops: Arc<AtomicUsize>,
+}
+
+impl Shared {
+    fn new(value: i32, counter: &Arc<AtomicUsize>) -> Self {
+        Shared {
+            key: value,
+            drops: counter.clone(),
+        }
+    }
+}
+
+impl Drop for Shared {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, atomic::Ordering::SeqCst);
+    }
+}
 
 fn make_vec(size: usize) -> Vector<i32> {
     let mut a = Vector::new();
 
     for _ in 0..size {
         let len = a.len();
-        a.insert(len, &rand::random::<i32>());
+        a.insert(len, rand::random::<i32>());
     }
 
     a
@@ -65,3 +114,510 @@ fn test_quick_sort() {
     a.quick_sort(|a, b| a.cmp(b));
     assert_eq!(a, b);
 }
+
+#[test]
+fn test_heap_sort() {
+    let (mut a, b) = do_test(10000);
+    a.heap_sort(|a, b| a.cmp(b));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_non_copy() {
+    let a: Vec<String> = (0..500)
+        .map(|_| rand::random::<u16>().to_string())
+        .collect();
+    let mut b = a.clone();
+    b.sort();
+    let b = Vector::from_slice(&b);
+
+    let sorts: [fn(&mut Vector<String>); 8] = [
+        |v| v.bubble_sort(|a, b| a.cmp(b)),
+        |v| v.selection_sort(|a, b| a.cmp(b)),
+        |v| v.insertion_sort(|a, b| a.cmp(b)),
+        |v| v.shell_sort(|a, b| a.cmp(b)),
+        |v| v.merge_sort(|a, b| a.cmp(b)),
+        |v| v.quick_sort(|a, b| a.cmp(b)),
+        |v| v.heap_sort(|a, b| a.cmp(b)),
+        |v| v.intro_sort(|a, b| a.cmp(b)),
+    ];
+
+    for sort in sorts.iter() {
+        let mut v = Vector::from_slice(&a);
+        sort(&mut v);
+        assert_eq!(v, b);
+    }
+}
+
+#[test]
+fn test_list_non_copy() {
+    let mut l = List::new();
+
+    for s in ["pear", "fig", "apple", "kiwi"].iter() {
+        let len = l.len();
+        l.insert(len, s.to_string());
+    }
+    l.merge_sort(|a, b| a.cmp(b));
+
+    let v: Vec<&str> = l.iter().map(|s| s.as_str()).collect();
+    assert_eq!(v, vec!["apple", "fig", "kiwi", "pear"]);
+}
+
+#[test]
+fn test_tim_sort() {
+    let (mut a, b) = do_test(10000);
+    a.tim_sort(|a, b| a.cmp(b));
+    assert_eq!(a, b);
+
+    let mut c = Vector::new();
+    for i in 0..10000 {
+        let len = c.len();
+        c.insert(len, if i % 1000 == 0 { -i } else { i });
+    }
+    let mut d = c.clone();
+    d.sort();
+    c.tim_sort(|a, b| a.cmp(b));
+    assert_eq!(c, d);
+
+    c.tim_sort(|a, b| b.cmp(a));
+    d.reverse();
+    assert_eq!(c, d);
+}
+
+#[test]
+fn test_stable_sort() {
+    let pairs: Vec<(u8, usize)> = (0..5000).map(|i| (rand::random::<u8>() % 16, i)).collect();
+    let mut sorted = pairs.clone();
+    sorted.sort_by_key(|p| p.0);
+
+    let mut v = Vector::from_slice(&pairs);
+    v.tim_sort(|a, b| a.0.cmp(&b.0));
+    assert_eq!(&*v, &sorted[..]);
+
+    let mut l = List::new();
+    for p in pairs.iter() {
+        let len = l.len();
+        l.insert(len, *p);
+    }
+    l.merge_sort(|a, b| a.0.cmp(&b.0));
+    let v: Vec<(u8, usize)> = l.iter().map(|p| *p).collect();
+    assert_eq!(v, sorted);
+}
+
+#[test]
+fn test_list_merge_sort() {
+    let (a, b) = do_test(10000);
+    let mut l = List::new();
+
+    for x in a.iter() {
+        let len = l.len();
+        l.insert(len, *x);
+    }
+    l.merge_sort(|a, b| a.cmp(b));
+
+    let v: Vec<i32> = l.iter().map(|x| *x).collect();
+    assert_eq!(&v[..], &*b);
+
+    l.merge_sort(|a, b| b.cmp(a));
+    let v: Vec<i32> = l.iter().rev().map(|x| *x).collect();
+    assert_eq!(&v[..], &*b);
+}
+
+#[test]
+fn test_intro_sort() {
+    let (mut a, b) = do_test(10000);
+    a.intro_sort(|a, b| a.cmp(b));
+    assert_eq!(a, b);
+
+    let patterns: [fn(i32) -> i32; 5] = [
+        |i| i,
+        |i| -i,
+        |_| 7,
+        |i| i % 3,
+        |i| if i < 50000 { i } else { 100000 - i },
+    ];
+
+    for pattern in patterns.iter() {
+        let mut a = Vector::new();
+        for i in 0..100000 {
+            let len = a.len();
+            a.insert(len, pattern(i));
+        }
+        let mut b = a.clone();
+        b.sort();
+
+        let count = std::cell::Cell::new(0usize);
+        a.intro_sort(|a, b| {
+            count.set(count.get() + 1);
+            a.cmp(b)
+        });
+        assert_eq!(a, b);
+        assert!(count.get() < 100000 * 17 * 3);
+    }
+}
+
+#[test]
+fn test_list_intro_sort() {
+    let (a, b) = do_test(10000);
+    let mut l = List::new();
+
+    for x in a.iter() {
+        let len = l.len();
+        l.insert(len, *x);
+    }
+    l.quick_sort(|a, b| a.cmp(b));
+
+    let v: Vec<i32> = l.iter().map(|x| *x).collect();
+    assert_eq!(&v[..], &*b);
+}
+
+#[test]
+fn test_radix_sort() {
+    let (a, b) = do_test(10000);
+
+    let mut c = a.clone();
+    c.lsd_radix_sort(|x| *x);
+    assert_eq!(c, b);
+
+    let mut c = a.clone();
+    c.msd_radix_sort(|x| *x);
+    assert_eq!(c, b);
+
+    let words: Vec<[u8; 3]> = (0..5000)
+        .map(|_| [rand::random::<u8>() % 4, rand::random(), rand::random()])
+        .collect();
+    let mut sorted = words.clone();
+    sorted.sort();
+
+    let mut v = Vector::from_slice(&words);
+    v.msd_radix_sort(|w| *w);
+    assert_eq!(&*v, &sorted[..]);
+
+    let mut v = Vector::from_slice(&words);
+    v.lsd_radix_sort(|w| *w);
+    assert_eq!(&*v, &sorted[..]);
+}
+
+#[test]
+fn test_linear_stable() {
+    let pairs: Vec<(u64, usize)> = (0..5000).map(|i| (rand::random::<u64>() % 64, i)).collect();
+    let mut sorted = pairs.clone();
+    sorted.sort_by_key(|p| p.0);
+
+    let mut v = Vector::from_slice(&pairs);
+    v.lsd_radix_sort(|p| p.0);
+    assert_eq!(&*v, &sorted[..]);
+
+    let mut v = Vector::from_slice(&pairs);
+    v.msd_radix_sort(|p| p.0);
+    assert_eq!(&*v, &sorted[..]);
+
+    let mut v = Vector::from_slice(&pairs);
+    v.counting_sort(|p| p.0 as usize + 1000);
+    assert_eq!(&*v, &sorted[..]);
+
+    let mut v = Vector::from_slice(&pairs);
+    v.bucket_sort(|p| p.0 as f64);
+    assert_eq!(&*v, &sorted[..]);
+}
+
+#[test]
+fn test_bucket_sort() {
+    let mut a: Vec<f64> = (0..10000)
+        .map(|_| rand::random::<f64>() * 2e6 - 1e6)
+        .collect();
+    a.extend_from_slice(&[
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        -f64::NAN,
+        f64::MAX,
+        f64::MIN,
+        0.0,
+        -0.0,
+    ]);
+    let mut b = a.clone();
+    b.sort_by(|x, y| x.total_cmp(y));
+
+    let mut v = Vector::from_slice(&a);
+    v.bucket_sort(|x| *x);
+    let v: Vec<u64> = v.iter().map(|x| x.to_bits()).collect();
+    let b: Vec<u64> = b.iter().map(|x| x.to_bits()).collect();
+    assert_eq!(v, b);
+}
+
+#[test]
+fn test_linear_skewed_keys() {
+    let pairs: Vec<(u64, usize)> = (0..100000)
+        .map(|i| (rand::random::<u64>() % 64, i))
+        .collect();
+    let mut sorted = pairs.clone();
+    sorted.sort_by_key(|p| p.0);
+
+    let mut v = Vector::from_slice(&pairs);
+    v.bucket_sort(|_| 1.0);
+    assert_eq!(&*v, &pairs[..]);
+
+    let mut outlier: Vec<(u64, usize)> = sorted.iter().filter(|p| p.1 != 0).cloned().collect();
+    outlier.push(pairs[0]);
+    let mut v = Vector::from_slice(&pairs);
+    v.bucket_sort(|p| if p.1 == 0 { 1e300 } else { p.0 as f64 });
+    assert_eq!(&*v, &outlier[..]);
+
+    let mut v = Vector::from_slice(&pairs);
+    v.counting_sort(|p| (p.0 as usize) << 58);
+    assert_eq!(&*v, &sorted[..]);
+
+    let mut split: Vec<(u64, usize)> = pairs.iter().filter(|p| p.0 < 32).cloned().collect();
+    split.extend(pairs.iter().filter(|p| p.0 >= 32));
+    let mut v = Vector::from_slice(&pairs);
+    v.counting_sort(|p| if p.0 < 32 { 0 } else { usize::MAX });
+    assert_eq!(&*v, &split[..]);
+}
+
+#[test]
+fn test_par_sort() {
+    let (a, b) = do_test(100000);
+
+    let mut c = a.clone();
+    c.par_merge_sort(|a, b| a.cmp(b));
+    assert_eq!(c, b);
+
+    let mut c = a.clone();
+    c.par_quick_sort(|a, b| a.cmp(b));
+    assert_eq!(c, b);
+
+    for &(workers, cutoff) in [(0, 0), (2, 1), (3, 100), (8, 1000)].iter() {
+        let parallelism = Parallelism::new(workers, cutoff);
+
+        let mut c = a.clone();
+        c.par_merge_sort_with(parallelism, |a, b| a.cmp(b));
+        assert_eq!(c, b);
+
+        let mut c = a.clone();
+        c.par_quick_sort_with(parallelism, |a, b| a.cmp(b));
+        assert_eq!(c, b);
+    }
+}
+
+#[test]
+fn test_par_merge_sort_stable() {
+    let pairs: Vec<(u8, usize)> = (0..50000).map(|i| (rand::random::<u8>() % 16, i)).collect();
+    let mut sorted = pairs.clone();
+    sorted.sort_by_key(|p| p.0);
+
+    for &workers in [1, 2, 5, 16].iter() {
+        let mut v = Vector::from_slice(&pairs);
+        v.par_merge_sort_with(Parallelism::new(workers, 64), |a, b| a.0.cmp(&b.0));
+        assert_eq!(&*v, &sorted[..]);
+    }
+}
+
+fn make_list(a: &Vector<i32>) -> List<i32> {
+    let mut l = List::new();
+
+    for x in a.iter() {
+        let len = l.len();
+        l.insert(len, *x);
+    }
+
+    l
+}
+
+#[test]
+fn test_select_nth() {
+    let (a, b) = do_test(10000);
+
+    for &n in [0, 1, 17, 5000, 9998, 9999].iter() {
+        let mut c = a.clone();
+        c.select_nth(n, |a, b| a.cmp(b));
+        assert_eq!(c[n], b[n]);
+        assert!(c.iter().take(n).all(|x| *x <= c[n]));
+        assert!(c.iter().skip(n).all(|x| *x >= c[n]));
+
+        let mut l = make_list(&a);
+        l.select_nth(n, |a, b| a.cmp(b));
+        assert_eq!(l[n], b[n]);
+    }
+
+    let mut c = Vector::new();
+    for i in 0..10000 {
+        let len = c.len();
+        c.insert(len, i % 10);
+    }
+    c.select_nth(5000, |a, b| a.cmp(b));
+    assert_eq!(c[5000], 5);
+}
+
+#[test]
+#[should_panic]
+fn test_select_nth_out_of_bound() {
+    let (mut a, _) = do_test(10);
+    a.select_nth(10, |a, b| a.cmp(b));
+}
+
+#[test]
+fn test_partial_sort() {
+    let (a, b) = do_test(10000);
+
+    for &k in [0, 1, 100, 9999, 10000, 20000].iter() {
+        let n = std::cmp::min(k, 10000);
+
+        let mut c = a.clone();
+        c.partial_sort(k, |a, b| a.cmp(b));
+        assert_eq!(&(*c)[..n], &(*b)[..n]);
+
+        let mut l = make_list(&a);
+        l.partial_sort(k, |a, b| a.cmp(b));
+        let v: Vec<i32> = l.iter().take(n).map(|x| *x).collect();
+        assert_eq!(&v[..], &(*b)[..n]);
+    }
+}
+
+#[test]
+fn test_top_k() {
+    let (mut a, b) = do_test(10000);
+    let smallest: Vec<i32> = b.iter().take(10).cloned().collect();
+    let largest: Vec<i32> = b.iter().rev().take(10).cloned().collect();
+
+    assert_eq!(&*a.top_k(10, |a, b| a.cmp(b)), &smallest[..]);
+    assert_eq!(&*make_list(&a).top_k(10, |a, b| a.cmp(b)), &smallest[..]);
+    assert_eq!(&*a.top_k(10, |a, b| b.cmp(a)), &largest[..]);
+    assert_eq!(a.top_k(0, |a, b| a.cmp(b)).len(), 0);
+    assert_eq!(a.top_k(20000, |a, b| a.cmp(b)), b);
+}
+
+#[test]
+fn test_is_sorted_by() {
+    let (mut a, mut b) = do_test(1000);
+
+    assert!(b.is_sorted_by(|a, b| a.cmp(b)));
+    assert!(make_list(&b).is_sorted_by(|a, b| a.cmp(b)));
+    assert!(!a.is_sorted_by(|a, b| a.cmp(b)));
+    assert!(Vector::<i32>::new().is_sorted_by(|a, b| a.cmp(b)));
+
+    a.partial_sort(1000, |a, b| b.cmp(a));
+    assert!(a.is_sorted_by(|a, b| b.cmp(a)));
+}
+
+#[test]
+fn test_sort_panic() {
+    let drops = Rc::new(Cell::new(0));
+    let mut v = Vector::new();
+
+    for i in 0..64 {
+        v.push(Counted::new(64 - i, &drops));
+    }
+
+    let calls = Cell::new(0);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        v.merge_sort(|a, b| {
+            calls.set(calls.get() + 1);
+            if calls.get() == 100 {
+                panic!("compare failed");
+            }
+            a.key.cmp(&b.key)
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 0);
+
+    let mut keys: Vec<i32> = v.iter().map(|c| c.key).collect();
+    keys.sort();
+    assert_eq!(keys, (1..65).collect::<Vec<i32>>());
+
+    drop(v);
+    assert_eq!(drops.get(), 64);
+}
+
+#[test]
+fn test_tim_sort_panic() {
+    for &limit in [50, 1000, 4000, 8000].iter() {
+        let drops = Rc::new(Cell::new(0));
+        let mut v = Vector::new();
+
+        for i in 0..1000 {
+            v.push(Counted::new((i * 7919) % 1000, &drops));
+        }
+
+        let calls = Cell::new(0);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            v.tim_sort(|a, b| {
+                calls.set(calls.get() + 1);
+                if calls.get() == limit {
+                    panic!("compare failed");
+                }
+                a.key.cmp(&b.key)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 0);
+
+        let mut keys: Vec<i32> = v.iter().map(|c| c.key).collect();
+        keys.sort();
+        assert_eq!(keys, (0..1000).collect::<Vec<i32>>());
+
+        drop(v);
+        assert_eq!(drops.get(), 1000);
+    }
+}
+
+#[test]
+fn test_list_merge_sort_panic() {
+    let drops = Rc::new(Cell::new(0));
+    let mut l = List::new();
+
+    for i in 0..200 {
+        l.insert(0, Counted::new((i * 37) % 200, &drops));
+    }
+
+    let calls = Cell::new(0);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        l.merge_sort(|a, b| {
+            calls.set(calls.get() + 1);
+            if calls.get() == 500 {
+                panic!("compare failed");
+            }
+            a.key.cmp(&b.key)
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(l.len(), 200);
+    assert_eq!(drops.get(), 0);
+
+    let mut keys: Vec<i32> = l.iter().map(|c| c.key).collect();
+    keys.sort();
+    assert_eq!(keys, (0..200).collect::<Vec<i32>>());
+
+    drop(l);
+    assert_eq!(drops.get(), 200);
+}
+
+#[test]
+fn test_par_sort_panic() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let mut v = Vector::new();
+
+    for i in 0..4000 {
+        v.push(Shared::new((i * 7919) % 4000, &drops));
+    }
+
+    let calls = AtomicUsize::new(0);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        v.par_merge_sort_with(Parallelism::new(4, 256), |a, b| {
+            if calls.fetch_add(1, atomic::Ordering::SeqCst) == 20000 {
+                panic!("compare failed");
+            }
+            a.key.cmp(&b.key)
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.load(atomic::Ordering::SeqCst), 0);
+
+    let mut keys: Vec<i32> = v.iter().map(|c| c.key).collect();
+    keys.sort();
+    assert_eq!(keys, (0..4000).collect::<Vec<i32>>());
+
+    drop(v);
+    assert_eq!(drops.load(atomic::Ordering::SeqCst), 4000);
+}
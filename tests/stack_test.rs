@@ -7,10 +7,22 @@ use rust_data_structure::vector::*;
 fn test_pop_push() {
     let mut s = Vector::new();
 
-    s.push(&43);
+    s.push(43);
     assert_eq!(*s.top(), 43);
-    s.push(&64);
+    s.push(64);
     assert_eq!(s.len(), 2);
     assert_eq!(64, s.pop());
     assert_eq!(1, s.len());
 }
+
+#[test]
+fn test_try_pop() {
+    let mut s = Vector::new();
+
+    assert_eq!(s.try_top(), None);
+    assert_eq!(s.try_pop(), None);
+    s.push(5);
+    assert_eq!(s.try_top(), Some(&mut 5));
+    assert_eq!(s.try_pop(), Some(5));
+    assert_eq!(s.try_pop(), None);
+}
@@ -1,4 +1,5 @@
 extern crate rust_data_structure;
+use rust_data_structure::utility::IndexErr;
 use rust_data_structure::vector::*;
 
 #[test]
@@ -6,7 +7,7 @@ fn test_base() {
     let v = Vector::<i32>::new();
     assert_eq!(v.capacity(), 8);
     assert_eq!(v.len(), 0);
-    assert_eq!(v.empty(), true);
+    assert!(v.empty());
 }
 
 #[test]
@@ -22,7 +23,7 @@ fn test_iter() {
     let mut l = Vector::from_slice(&[1234, 3, 3, 3, 6, 0, 54, 531, 213, 0, 0]);
     let arr = [1, 1, 1, 1, 1, 1, 54, 531, 213, 0, 0];
 
-    for n in l.iter().take(6) {
+    for n in l.iter_mut().take(6) {
         *n = 1;
     }
 
@@ -35,6 +36,28 @@ fn test_iter() {
     }
 }
 
+#[test]
+fn test_into_iter() {
+    let mut v = Vector::from_slice(&[3, 1, 4, 1, 5]);
+
+    for n in &mut v {
+        *n *= 2;
+    }
+
+    let mut sum = 0;
+    for n in &v {
+        sum += *n;
+    }
+    assert_eq!(sum, 28);
+    assert_eq!(v.iter().len(), 5);
+
+    let mut it = v.into_iter();
+    assert_eq!(it.next(), Some(6));
+    assert_eq!(it.next_back(), Some(10));
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.collect::<Vec<_>>(), vec![2, 8, 2]);
+}
+
 #[test]
 fn test_find() {
     let v = Vector::from_slice(&[1, 34, 354, 23]);
@@ -46,7 +69,7 @@ fn test_find() {
 #[test]
 fn test_insert() {
     let mut v = Vector::from_slice(&[1231, 423, 12, 6]);
-    v.insert(2, &8);
+    v.insert(2, 8);
     assert_eq!(v[2], 8);
     assert_eq!(v[4], 6);
     assert_eq!(v.len(), 5);
@@ -78,3 +101,125 @@ fn test_deref() {
     (*v)[3] = 9;
     assert_eq!(v[3], 9);
 }
+
+#[test]
+fn test_clone() {
+    let mut v = Vector::new();
+    for i in 0..20 {
+        v.insert(i, i.to_string());
+    }
+
+    let mut o = v.clone();
+    o[0].push('!');
+    assert_eq!(v[0], "0");
+    assert_eq!(o[0], "0!");
+    assert_eq!(o.len(), 20);
+
+    let capacity = o.capacity();
+    o.clone_from(&Vector::from_slice(&[String::from("a"), String::from("b")]));
+    assert_eq!(o, Vector::from_slice(&[String::from("a"), String::from("b")]));
+    assert_eq!(o.capacity(), capacity);
+
+    o.clone_from(&v);
+    assert_eq!(o, v);
+}
+
+#[test]
+fn test_capacity() {
+    let mut v = Vector::with_capacity(100);
+    assert_eq!(v.capacity(), 100);
+
+    for i in 0..10 {
+        v.insert(i, i);
+    }
+    v.reserve(50);
+    assert_eq!(v.capacity(), 100);
+    v.reserve(200);
+    assert!(v.capacity() >= 210);
+    v.reserve_exact(300);
+    assert_eq!(v.capacity(), 310);
+
+    v.shrink_to_fit();
+    assert_eq!(v.capacity(), 10);
+
+    v.truncate(4);
+    assert_eq!(v.len(), 4);
+    assert_eq!(v[3], 3);
+    assert_eq!(v.capacity(), 10);
+
+    v.clear();
+    assert!(v.empty());
+    assert_eq!(v.capacity(), 10);
+}
+
+#[test]
+fn test_growth() {
+    let mut v = Vector::with_growth(OneAndHalf);
+    for i in 0..9 {
+        v.insert(i, i);
+    }
+    assert_eq!(v.capacity(), 12);
+
+    let mut v = Vector::with_growth(Increment(4));
+    for i in 0..9 {
+        v.insert(i, i);
+    }
+    assert_eq!(v.capacity(), 12);
+    v.remove(0, 9);
+    assert_eq!(v.capacity(), 8);
+
+    let mut v = Vector::with_growth(NoShrink(Doubling));
+    for i in 0..100 {
+        v.insert(i, i);
+    }
+    assert_eq!(v.capacity(), 128);
+    v.remove(0, 99);
+    assert_eq!(v.capacity(), 128);
+    assert_eq!(v[0], 99);
+}
+
+#[test]
+fn test_try() {
+    let mut v = Vector::from_slice(&[1, 2, 3]);
+
+    assert_eq!(v.get(2), Some(&3));
+    assert_eq!(v.get(3), None);
+    *v.get_mut(0).unwrap() = 7;
+    assert_eq!(v[0], 7);
+    assert_eq!(v.get_mut(5), None);
+
+    assert_eq!(
+        v.try_insert(4, 0),
+        Err(IndexErr::OutOfBounds { index: 4, len: 3 })
+    );
+    assert_eq!(v.try_insert(3, 4), Ok(()));
+    assert_eq!(
+        v.try_remove(2, 5),
+        Err(IndexErr::OutOfBounds { index: 5, len: 4 })
+    );
+    assert_eq!(
+        v.try_remove(3, 2),
+        Err(IndexErr::InvalidRange { lo: 3, hi: 2 })
+    );
+    assert_eq!(v.try_remove(1, 3), Ok(()));
+    assert_eq!(v, Vector::from_slice(&[7, 4]));
+}
+
+#[test]
+fn test_zero_sized() {
+    let mut v = Vector::from_slice(&[(), (), ()]);
+
+    assert_eq!(v.iter().len(), 3);
+    assert_eq!(v.iter().count(), 3);
+    assert_eq!(v.iter().rev().count(), 3);
+    assert_eq!(v.iter_mut().count(), 3);
+
+    let mut it = v.clone().into_iter();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.next_back(), Some(()));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.count(), 2);
+
+    v.quick_sort(|a, b| a.cmp(b));
+    assert_eq!(v.len(), 3);
+}